@@ -136,7 +136,8 @@ impl Test<'_> {
         println!("\t\t\tWaiting...");
         global
             .device_poll(device_id, wgt::Maintain::wait())
-            .unwrap();
+            .unwrap()
+            .panic_on_timeout();
 
         for expect in self.expectations {
             println!("\t\t\tChecking {}", expect.name);
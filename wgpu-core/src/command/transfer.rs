@@ -1070,8 +1070,10 @@ impl Global {
 
         // src and dst texture format must be copy-compatible
         // https://gpuweb.github.io/gpuweb/#copy-compatible
-        if src_texture.desc.format.remove_srgb_suffix()
-            != dst_texture.desc.format.remove_srgb_suffix()
+        if !src_texture
+            .desc
+            .format
+            .copy_compatible_with(dst_texture.desc.format)
         {
             return Err(TransferError::TextureFormatsNotCopyCompatible {
                 src_format: src_texture.desc.format,
@@ -1693,17 +1693,21 @@ impl Global {
                 // Automatic present mode checks.
                 //
                 // The "Automatic" modes are never supported by the backends.
-                let fallbacks = match config.present_mode {
-                    wgt::PresentMode::AutoVsync => {
-                        &[wgt::PresentMode::FifoRelaxed, wgt::PresentMode::Fifo][..]
+                // Their fallbacks always end in FIFO to make sure it's always supported.
+                let new_mode = match config.present_mode.resolve(&caps.present_modes) {
+                    Some(mode) => mode,
+                    None if matches!(
+                        config.present_mode,
+                        wgt::PresentMode::AutoVsync | wgt::PresentMode::AutoNoVsync
+                    ) =>
+                    {
+                        unreachable!(
+                            "Fallback system failed to choose present mode. \
+                            This is a bug. Mode: {:?}, Options: {:?}",
+                            config.present_mode, &caps.present_modes
+                        );
                     }
-                    // Always end in FIFO to make sure it's always supported
-                    wgt::PresentMode::AutoNoVsync => &[
-                        wgt::PresentMode::Immediate,
-                        wgt::PresentMode::Mailbox,
-                        wgt::PresentMode::Fifo,
-                    ][..],
-                    _ => {
+                    None => {
                         return Err(E::UnsupportedPresentMode {
                             requested: config.present_mode,
                             available: caps.present_modes.clone(),
@@ -1711,18 +1715,6 @@ impl Global {
                     }
                 };
 
-                let new_mode = fallbacks
-                    .iter()
-                    .copied()
-                    .find(|fallback| caps.present_modes.contains(fallback))
-                    .unwrap_or_else(|| {
-                        unreachable!(
-                            "Fallback system failed to choose present mode. \
-                            This is a bug. Mode: {:?}, Options: {:?}",
-                            config.present_mode, &caps.present_modes
-                        );
-                    });
-
                 api_log!(
                     "Automatically choosing presentation mode by rule {:?}. Chose {new_mode:?}",
                     config.present_mode
@@ -1739,32 +1731,25 @@ impl Global {
                 .composite_alpha_modes
                 .contains(&config.composite_alpha_mode)
             {
-                let new_alpha_mode = 'alpha: {
-                    // Automatic alpha mode checks.
-                    let fallbacks = match config.composite_alpha_mode {
-                        wgt::CompositeAlphaMode::Auto => &[
-                            wgt::CompositeAlphaMode::Opaque,
-                            wgt::CompositeAlphaMode::Inherit,
-                        ][..],
-                        _ => {
-                            return Err(E::UnsupportedAlphaMode {
-                                requested: config.composite_alpha_mode,
-                                available: caps.composite_alpha_modes.clone(),
-                            });
-                        }
-                    };
-
-                    for &fallback in fallbacks {
-                        if caps.composite_alpha_modes.contains(&fallback) {
-                            break 'alpha fallback;
-                        }
+                // Automatic alpha mode checks.
+                let new_alpha_mode = match config
+                    .composite_alpha_mode
+                    .resolve(&caps.composite_alpha_modes)
+                {
+                    Some(mode) => mode,
+                    None if config.composite_alpha_mode == wgt::CompositeAlphaMode::Auto => {
+                        unreachable!(
+                            "Fallback system failed to choose alpha mode. This is a bug. \
+                                      AlphaMode: {:?}, Options: {:?}",
+                            config.composite_alpha_mode, &caps.composite_alpha_modes
+                        );
+                    }
+                    None => {
+                        return Err(E::UnsupportedAlphaMode {
+                            requested: config.composite_alpha_mode,
+                            available: caps.composite_alpha_modes.clone(),
+                        });
                     }
-
-                    unreachable!(
-                        "Fallback system failed to choose alpha mode. This is a bug. \
-                                  AlphaMode: {:?}, Options: {:?}",
-                        config.composite_alpha_mode, &caps.composite_alpha_modes
-                    );
                 };
 
                 api_log!(
@@ -1927,24 +1912,22 @@ impl Global {
 
     /// Check `device_id` for freeable resources and completed buffer mappings.
     ///
-    /// Return `queue_empty` indicating whether there are more queue submissions still in flight.
+    /// Return whether waiting for submissions timed out, or otherwise whether
+    /// there are more queue submissions still in flight.
     pub fn device_poll(
         &self,
         device_id: DeviceId,
         maintain: wgt::Maintain<crate::SubmissionIndex>,
-    ) -> Result<bool, WaitIdleError> {
+    ) -> Result<wgt::MaintainResult, WaitIdleError> {
         api_log!("Device::poll {maintain:?}");
 
         let device = self.hub.devices.get(device_id);
 
-        let DevicePoll {
-            closures,
-            queue_empty,
-        } = Self::poll_single_device(&device, maintain)?;
+        let DevicePoll { closures, result } = Self::poll_single_device(&device, maintain)?;
 
         closures.fire();
 
-        Ok(queue_empty)
+        Ok(result)
     }
 
     fn poll_single_device(
@@ -1953,16 +1936,13 @@ impl Global {
     ) -> Result<DevicePoll, WaitIdleError> {
         let snatch_guard = device.snatchable_lock.read();
         let fence = device.fence.read();
-        let (closures, queue_empty) = device.maintain(fence, maintain, snatch_guard)?;
+        let (closures, result) = device.maintain(fence, maintain, snatch_guard)?;
 
         // Some deferred destroys are scheduled in maintain so run this right after
         // to avoid holding on to them until the next device poll.
         device.deferred_resource_destruction();
 
-        Ok(DevicePoll {
-            closures,
-            queue_empty,
-        })
+        Ok(DevicePoll { closures, result })
     }
 
     /// Poll all devices belonging to the specified backend.
@@ -1992,10 +1972,10 @@ impl Global {
 
                 let DevicePoll {
                     closures: cbs,
-                    queue_empty,
+                    result,
                 } = Self::poll_single_device(device, maintain)?;
 
-                all_queue_empty &= queue_empty;
+                all_queue_empty &= result.is_queue_empty();
 
                 closures.extend(cbs);
             }
@@ -2268,5 +2248,5 @@ impl Global {
 
 struct DevicePoll {
     closures: UserClosures,
-    queue_empty: bool,
+    result: wgt::MaintainResult,
 }
@@ -871,7 +871,7 @@ impl Queue {
 
         let mut needs_flag = false;
         needs_flag |= matches!(source.source, wgt::ExternalImageSource::OffscreenCanvas(_));
-        needs_flag |= source.origin != wgt::Origin2d::ZERO;
+        needs_flag |= !source.origin.is_zero();
         needs_flag |= destination.color_space != wgt::PredefinedColorSpace::Srgb;
         #[allow(clippy::bool_comparison)]
         if matches!(source.source, wgt::ExternalImageSource::ImageBitmap(_)) {
@@ -375,20 +375,20 @@ impl Device {
     /// The `maintain` argument tells how the maintenance function should behave, either
     /// blocking or just polling the current state of the gpu.
     ///
-    /// Return a pair `(closures, queue_empty)`, where:
+    /// Return a pair `(closures, result)`, where:
     ///
     /// - `closures` is a list of actions to take: mapping buffers, notifying the user
     ///
-    /// - `queue_empty` is a boolean indicating whether there are more queue
-    ///   submissions still in flight. (We have to take the locks needed to
-    ///   produce this information for other reasons, so we might as well just
-    ///   return it to our callers.)
+    /// - `result` tells whether a requested wait timed out, and otherwise whether
+    ///   there are more queue submissions still in flight. (We have to take the
+    ///   locks needed to produce this information for other reasons, so we might
+    ///   as well just return it to our callers.)
     pub(crate) fn maintain<'this>(
         &'this self,
         fence: crate::lock::RwLockReadGuard<ManuallyDrop<Box<dyn hal::DynFence>>>,
         maintain: wgt::Maintain<crate::SubmissionIndex>,
         snatch_guard: SnatchGuard,
-    ) -> Result<(UserClosures, bool), WaitIdleError> {
+    ) -> Result<(UserClosures, wgt::MaintainResult), WaitIdleError> {
         profiling::scope!("Device::maintain");
 
         // Determine which submission index `maintain` represents.
@@ -415,13 +415,19 @@ impl Device {
         };
 
         // If necessary, wait for that submission to complete.
+        let mut wait_completed = true;
         if maintain.is_wait() {
             log::trace!("Device::maintain: waiting for submission index {submission_index}");
-            unsafe {
+            wait_completed = unsafe {
                 self.raw()
                     .wait(fence.as_ref(), submission_index, CLEANUP_WAIT_MS)
             }
             .map_err(|e| self.handle_hal_error(e))?;
+            if !wait_completed {
+                log::warn!(
+                    "Device::maintain: timed out waiting for submission index {submission_index}"
+                );
+            }
         }
 
         let (submission_closures, mapping_closures, queue_empty) =
@@ -467,7 +473,8 @@ impl Device {
             submissions: submission_closures,
             device_lost_invocations,
         };
-        Ok((closures, queue_empty))
+        let result = wgt::MaintainResult::from_wait(wait_completed, queue_empty);
+        Ok((closures, result))
     }
 
     pub(crate) fn create_buffer(
@@ -498,19 +505,9 @@ impl Device {
             return Err(resource::CreateBufferError::InvalidUsage(desc.usage));
         }
 
-        if !self
-            .features
-            .contains(wgt::Features::MAPPABLE_PRIMARY_BUFFERS)
-        {
-            use wgt::BufferUsages as Bu;
-            let write_mismatch = desc.usage.contains(Bu::MAP_WRITE)
-                && !(Bu::MAP_WRITE | Bu::COPY_SRC).contains(desc.usage);
-            let read_mismatch = desc.usage.contains(Bu::MAP_READ)
-                && !(Bu::MAP_READ | Bu::COPY_DST).contains(desc.usage);
-            if write_mismatch || read_mismatch {
-                return Err(resource::CreateBufferError::UsageMismatch(desc.usage));
-            }
-        }
+        desc.usage
+            .validate_mapping(self.features)
+            .map_err(|err| resource::CreateBufferError::UsageMismatch(err.usage))?;
 
         let mut usage = conv::map_buffer_usage(desc.usage);
 
@@ -1046,19 +1043,12 @@ impl Device {
                 .unwrap_or(texture.desc.format)
         });
 
-        let resolved_dimension = desc
-            .dimension
-            .unwrap_or_else(|| match texture.desc.dimension {
-                wgt::TextureDimension::D1 => TextureViewDimension::D1,
-                wgt::TextureDimension::D2 => {
-                    if texture.desc.array_layer_count() == 1 {
-                        TextureViewDimension::D2
-                    } else {
-                        TextureViewDimension::D2Array
-                    }
-                }
-                wgt::TextureDimension::D3 => TextureViewDimension::D3,
-            });
+        let resolved_dimension = desc.dimension.unwrap_or_else(|| {
+            texture
+                .desc
+                .dimension
+                .default_view_dimension(texture.desc.array_layer_count())
+        });
 
         let resolved_mip_level_count = desc.range.mip_level_count.unwrap_or_else(|| {
             texture
@@ -3036,7 +3026,7 @@ impl Device {
             );
         }
 
-        if desc.primitive.strip_index_format.is_some() && !desc.primitive.topology.is_strip() {
+        if desc.primitive.validate_strip_index_format().is_err() {
             return Err(
                 pipeline::CreateRenderPipelineError::StripIndexFormatForNonStripTopology {
                     strip_index_format: desc.primitive.strip_index_format,
@@ -31,17 +31,15 @@ pub struct FailedLimit {
 }
 
 fn check_limits(requested: &wgt::Limits, allowed: &wgt::Limits) -> Vec<FailedLimit> {
-    let mut failed = Vec::new();
-
-    requested.check_limits_with_fail_fn(allowed, false, |name, requested, allowed| {
-        failed.push(FailedLimit {
-            name: Cow::Borrowed(name),
-            requested,
-            allowed,
+    requested
+        .check_limits_report(allowed)
+        .into_iter()
+        .map(|violation| FailedLimit {
+            name: Cow::Borrowed(violation.name),
+            requested: violation.requested,
+            allowed: violation.allowed,
         })
-    });
-
-    failed
+        .collect()
 }
 
 #[test]
@@ -132,6 +132,65 @@ pub struct HalCounters {
     pub memory_allocations: InternalCounter,
 }
 
+impl HalCounters {
+    /// Returns each counter along with its field name.
+    fn named_counters(&self) -> [(&'static str, &InternalCounter); 17] {
+        [
+            ("buffers", &self.buffers),
+            ("textures", &self.textures),
+            ("texture_views", &self.texture_views),
+            ("bind_groups", &self.bind_groups),
+            ("bind_group_layouts", &self.bind_group_layouts),
+            ("render_pipelines", &self.render_pipelines),
+            ("compute_pipelines", &self.compute_pipelines),
+            ("pipeline_layouts", &self.pipeline_layouts),
+            ("samplers", &self.samplers),
+            ("command_encoders", &self.command_encoders),
+            ("shader_modules", &self.shader_modules),
+            ("query_sets", &self.query_sets),
+            ("fences", &self.fences),
+            ("buffer_memory", &self.buffer_memory),
+            ("texture_memory", &self.texture_memory),
+            (
+                "acceleration_structure_memory",
+                &self.acceleration_structure_memory,
+            ),
+            ("memory_allocations", &self.memory_allocations),
+        ]
+    }
+
+    /// Computes the element-wise difference between these counters and `baseline`.
+    ///
+    /// All counters of the result are 0 if the `counters` feature is not enabled.
+    #[must_use]
+    pub fn diff(&self, baseline: &Self) -> Self {
+        let diff = Self::default();
+        for ((_, diff), ((_, current), (_, baseline))) in diff.named_counters().into_iter().zip(
+            self.named_counters()
+                .into_iter()
+                .zip(baseline.named_counters()),
+        ) {
+            diff.set(current.read() - baseline.read());
+        }
+        diff
+    }
+}
+
+/// Prints the nonzero counters as comma separated `name: value` pairs.
+impl fmt::Display for HalCounters {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut separator = "";
+        for (name, counter) in self.named_counters() {
+            let value = counter.read();
+            if value != 0 {
+                write!(f, "{separator}{name}: {value}")?;
+                separator = ", ";
+            }
+        }
+        Ok(())
+    }
+}
+
 /// `wgpu-core`'s internal counters.
 #[derive(Clone, Default)]
 pub struct CoreCounters {
@@ -147,6 +206,47 @@ pub struct InternalCounters {
     pub hal: HalCounters,
 }
 
+impl InternalCounters {
+    /// Computes the element-wise difference between these counters and `baseline`, for example
+    /// to find the resources created since a previous frame.
+    ///
+    /// All counters of the result are 0 if the `counters` feature is not enabled.
+    #[must_use]
+    pub fn diff(&self, baseline: &Self) -> Self {
+        Self {
+            core: CoreCounters::default(),
+            hal: self.hal.diff(&baseline.hal),
+        }
+    }
+}
+
+/// Prints the nonzero counters as comma separated `name: value` pairs.
+impl fmt::Display for InternalCounters {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.hal.fmt(f)
+    }
+}
+
+#[cfg(feature = "counters")]
+#[test]
+fn internal_counters_diff() {
+    use alloc::string::ToString;
+
+    let baseline = InternalCounters::default();
+    baseline.hal.buffers.set(3);
+    baseline.hal.textures.set(2);
+
+    let current = baseline.clone();
+    current.hal.buffers.add(2);
+    current.hal.texture_memory.add(1024);
+
+    let diff = current.diff(&baseline);
+    assert_eq!(diff.hal.buffers.read(), 2);
+    assert_eq!(diff.hal.textures.read(), 0);
+    assert_eq!(diff.to_string(), "buffers: 2, texture_memory: 1024");
+    assert_eq!(baseline.diff(&baseline).to_string(), "");
+}
+
 /// Describes an allocation in the [`AllocatorReport`].
 #[derive(Clone)]
 pub struct AllocationReport {
@@ -152,13 +152,20 @@ impl InstanceFlags {
     /// - `WGPU_ALLOW_UNDERLYING_NONCOMPLIANT_ADAPTER`
     /// - `WGPU_GPU_BASED_VALIDATION`
     #[must_use]
-    pub fn with_env(mut self) -> Self {
-        fn env(key: &str) -> Option<bool> {
-            crate::env::var(key).map(|s| match s.as_str() {
+    pub fn with_env(self) -> Self {
+        self.with_env_lookup(crate::env::var)
+    }
+
+    /// Implementation of [`Self::with_env()`], reading the environment variables through `var`.
+    ///
+    /// This allows testing the parsing without changing the process environment.
+    fn with_env_lookup(mut self, var: impl Fn(&str) -> Option<String>) -> Self {
+        let env = |key: &str| {
+            var(key).map(|s| match s.as_str() {
                 "0" => false,
                 _ => true,
             })
-        }
+        };
 
         if let Some(bit) = env("WGPU_VALIDATION") {
             self.set(Self::VALIDATION, bit);
@@ -178,6 +185,80 @@ impl InstanceFlags {
 
         self
     }
+
+    /// Returns the level of validation enabled by the flags.
+    ///
+    /// This is [`ValidationLevel::Advanced`] if [`Self::GPU_BASED_VALIDATION`] is set, as it
+    /// implies [`Self::VALIDATION`], [`ValidationLevel::Basic`] if only [`Self::VALIDATION`] is
+    /// set, and [`ValidationLevel::None`] otherwise.
+    #[must_use]
+    pub fn validation_level(&self) -> ValidationLevel {
+        if self.contains(Self::GPU_BASED_VALIDATION) {
+            ValidationLevel::Advanced
+        } else if self.contains(Self::VALIDATION) {
+            ValidationLevel::Basic
+        } else {
+            ValidationLevel::None
+        }
+    }
+}
+
+/// Level of validation enabled by a set of [`InstanceFlags`], as returned by
+/// [`InstanceFlags::validation_level`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ValidationLevel {
+    /// No validation is enabled.
+    None,
+    /// Validation is enabled with [`InstanceFlags::VALIDATION`].
+    Basic,
+    /// GPU-based validation is enabled with [`InstanceFlags::GPU_BASED_VALIDATION`].
+    Advanced,
+}
+
+#[test]
+fn instance_flags_validation_level() {
+    assert_eq!(
+        InstanceFlags::empty().validation_level(),
+        ValidationLevel::None
+    );
+    assert_eq!(
+        InstanceFlags::DEBUG.validation_level(),
+        ValidationLevel::None
+    );
+    assert_eq!(
+        InstanceFlags::debugging().validation_level(),
+        ValidationLevel::Basic
+    );
+    assert_eq!(
+        InstanceFlags::advanced_debugging().validation_level(),
+        ValidationLevel::Advanced
+    );
+    assert_eq!(
+        InstanceFlags::GPU_BASED_VALIDATION.validation_level(),
+        ValidationLevel::Advanced
+    );
+}
+
+#[test]
+fn instance_flags_discard_hal_labels_env() {
+    let discard_hal_labels = |value: Option<&'static str>| {
+        move |key: &str| match key {
+            "WGPU_DISCARD_HAL_LABELS" => value.map(String::from),
+            _ => None,
+        }
+    };
+
+    assert!(InstanceFlags::empty()
+        .with_env_lookup(discard_hal_labels(Some("1")))
+        .contains(InstanceFlags::DISCARD_HAL_LABELS));
+
+    assert!(!InstanceFlags::DISCARD_HAL_LABELS
+        .with_env_lookup(discard_hal_labels(Some("0")))
+        .contains(InstanceFlags::DISCARD_HAL_LABELS));
+
+    assert!(InstanceFlags::DISCARD_HAL_LABELS
+        .with_env_lookup(discard_hal_labels(None))
+        .contains(InstanceFlags::DISCARD_HAL_LABELS));
 }
 
 /// Options that are passed to a given backend.
@@ -19,14 +19,11 @@ use core::{
     hash::{Hash, Hasher},
     mem::size_of,
     num::NonZeroU32,
-    ops::Range,
+    ops::{Range, RangeInclusive},
 };
 
 #[cfg(any(feature = "serde", test))]
-use {
-    alloc::format,
-    serde::{Deserialize, Serialize},
-};
+use serde::{Deserialize, Serialize};
 
 pub mod assertions;
 mod counters;
@@ -37,6 +34,55 @@ pub mod math;
 pub use counters::*;
 pub use instance::*;
 
+/// Implements the helpers shared by all public bitflags types.
+macro_rules! impl_bitflags {
+    ($($name:ident),+ $(,)?) => {$(
+        impl $name {
+            /// Returns an iterator over the names of the flags that are set, in declaration order.
+            ///
+            /// Bits that don't correspond to a named flag, such as those kept by
+            /// `from_bits_retain`, are skipped.
+            pub fn iter_set_names(&self) -> impl Iterator<Item = &'static str> {
+                self.iter_names().map(|(name, _)| name)
+            }
+        }
+    )+};
+}
+
+impl_bitflags!(
+    AccelerationStructureFlags,
+    AccelerationStructureGeometryFlags,
+    Backends,
+    BufferUsages,
+    BufferUses,
+    ColorWrites,
+    DownlevelFlags,
+    Features,
+    InstanceFlags,
+    PipelineStatisticsTypes,
+    ShaderStages,
+    TextureAspectFlags,
+    TextureFormatFeatureFlags,
+    TextureUsages,
+    TextureUses,
+);
+
+#[test]
+fn bitflags_iter_set_names() {
+    assert!((Backends::VULKAN | Backends::GL)
+        .iter_set_names()
+        .eq(["VULKAN", "GL"]));
+    assert!(ColorWrites::ALL
+        .iter_set_names()
+        .eq(["RED", "GREEN", "BLUE", "ALPHA"]));
+    assert!(
+        ShaderStages::from_bits_retain(ShaderStages::VERTEX.bits() | 1 << 31)
+            .iter_set_names()
+            .eq(["VERTEX"])
+    );
+    assert_eq!(Features::empty().iter_set_names().count(), 0);
+}
+
 /// Integral type used for buffer offsets.
 pub type BufferAddress = u64;
 /// Integral type used for buffer slice sizes.
@@ -68,6 +114,46 @@ pub const QUERY_SET_MAX_QUERIES: u32 = 4096;
 /// Size of a single piece of query data.
 pub const QUERY_SIZE: u32 = 8;
 
+/// Rounds `size` up to a multiple of [`COPY_BUFFER_ALIGNMENT`], as required for buffer copies
+/// and clears.
+#[must_use]
+pub const fn align_copy_buffer(size: BufferAddress) -> BufferAddress {
+    math::align_up(size, COPY_BUFFER_ALIGNMENT)
+}
+
+/// Rounds `offset` up to a multiple of [`MAP_ALIGNMENT`], as required for mapping buffers.
+#[must_use]
+pub const fn align_map_offset(offset: BufferAddress) -> BufferAddress {
+    math::align_up(offset, MAP_ALIGNMENT)
+}
+
+/// Returns true if `x` is a multiple of [`COPY_BUFFER_ALIGNMENT`], so it can be used as an
+/// offset or size of a buffer copy or clear.
+#[must_use]
+pub const fn is_copy_aligned(x: BufferAddress) -> bool {
+    x % COPY_BUFFER_ALIGNMENT == 0
+}
+
+#[test]
+fn buffer_alignment_helpers() {
+    for x in 0..64 {
+        let copy = align_copy_buffer(x);
+        assert!(is_copy_aligned(copy));
+        assert!(copy >= x && copy - x < COPY_BUFFER_ALIGNMENT);
+        assert_eq!(align_copy_buffer(copy), copy);
+
+        let map = align_map_offset(x);
+        assert_eq!(map % MAP_ALIGNMENT, 0);
+        assert!(map >= x && map - x < MAP_ALIGNMENT);
+        assert_eq!(align_map_offset(map), map);
+    }
+    assert_eq!(align_copy_buffer(256), 256);
+    assert_eq!(align_copy_buffer(5), 8);
+    assert_eq!(align_map_offset(8), 8);
+    assert_eq!(align_map_offset(9), 16);
+    assert!(!is_copy_aligned(6));
+}
+
 /// Backends supported by wgpu.
 #[repr(u8)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -108,6 +194,36 @@ impl core::fmt::Display for Backend {
     }
 }
 
+/// Error returned when a string does not name a valid [`Backend`] or set of [`Backends`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ParseBackendError;
+
+impl core::fmt::Display for ParseBackendError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("invalid backend name")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseBackendError {}
+
+impl core::str::FromStr for Backend {
+    type Err = ParseBackendError;
+
+    /// Parses a backend from the names returned by [`Backend::to_str`].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "empty" => Backend::Empty,
+            "vulkan" => Backend::Vulkan,
+            "metal" => Backend::Metal,
+            "dx12" => Backend::Dx12,
+            "gl" => Backend::Gl,
+            "webgpu" => Backend::BrowserWebGpu,
+            _ => return Err(ParseBackendError),
+        })
+    }
+}
+
 /// Power Preference when choosing a physical adapter.
 ///
 /// Corresponds to [WebGPU `GPUPowerPreference`](
@@ -128,17 +244,78 @@ pub enum PowerPreference {
 
 impl PowerPreference {
     /// Get a power preference from the environment variable `WGPU_POWER_PREF`.
+    ///
+    /// The value is case insensitive, and accepts the same names as the
+    /// [`FromStr`](core::str::FromStr) implementation: `low` or `low-power`,
+    /// `high` or `high-performance`, and `none`. Returns `None` if the variable
+    /// is unset or contains any other value.
     pub fn from_env() -> Option<Self> {
         let env = crate::env::var("WGPU_POWER_PREF")?;
-        match env.to_lowercase().as_str() {
-            "low" => Some(Self::LowPower),
-            "high" => Some(Self::HighPerformance),
-            "none" => Some(Self::None),
-            _ => None,
+        env.to_lowercase().parse().ok()
+    }
+
+    /// Returns the name of the power preference, matching its serialized form.
+    #[must_use]
+    pub const fn to_str(self) -> &'static str {
+        match self {
+            Self::None => "none",
+            Self::LowPower => "low-power",
+            Self::HighPerformance => "high-performance",
         }
     }
 }
 
+impl core::fmt::Display for PowerPreference {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.to_str())
+    }
+}
+
+/// Error returned when a string does not name a valid [`PowerPreference`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ParsePowerPreferenceError;
+
+impl core::fmt::Display for ParsePowerPreferenceError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("invalid power preference")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParsePowerPreferenceError {}
+
+impl core::str::FromStr for PowerPreference {
+    type Err = ParsePowerPreferenceError;
+
+    /// Parses a power preference from the names returned by [`PowerPreference::to_str`],
+    /// or the short aliases `low` and `high`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "none" => Self::None,
+            "low" | "low-power" => Self::LowPower,
+            "high" | "high-performance" => Self::HighPerformance,
+            _ => return Err(ParsePowerPreferenceError),
+        })
+    }
+}
+
+#[test]
+fn power_preference_from_str() {
+    for pref in [
+        PowerPreference::None,
+        PowerPreference::LowPower,
+        PowerPreference::HighPerformance,
+    ] {
+        assert_eq!(pref.to_str().parse(), Ok(pref));
+    }
+    assert_eq!("low".parse(), Ok(PowerPreference::LowPower));
+    assert_eq!("high".parse(), Ok(PowerPreference::HighPerformance));
+    assert_eq!(
+        "fast".parse::<PowerPreference>(),
+        Err(ParsePowerPreferenceError)
+    );
+}
+
 bitflags::bitflags! {
     /// Represents the backends that wgpu will use.
     #[repr(transparent)]
@@ -195,7 +372,8 @@ impl From<Backend> for Backends {
 impl Backends {
     /// Gets a set of backends from the environment variable `WGPU_BACKEND`.
     ///
-    /// See [`Self::from_comma_list()`] for the format of the string.
+    /// Returns `None` if the variable is not set. See [`Self::from_comma_list()`] for the
+    /// format of the string; unknown backend names are logged and ignored.
     pub fn from_env() -> Option<Self> {
         let env = crate::env::var("WGPU_BACKEND")?;
         Some(Self::from_comma_list(&env))
@@ -222,17 +400,17 @@ impl Backends {
     /// - metal  = "metal" or "mtl"
     /// - gles   = "opengl" or "gles" or "gl"
     /// - webgpu = "webgpu"
+    /// - [`Self::PRIMARY`] = "primary"
+    /// - [`Self::SECONDARY`] = "secondary"
+    ///
+    /// Unknown names are logged and ignored. Use [`str::parse`] to reject them instead.
     pub fn from_comma_list(string: &str) -> Self {
         let mut backends = Self::empty();
         for backend in string.to_lowercase().split(',') {
-            backends |= match backend.trim() {
-                "vulkan" | "vk" => Self::VULKAN,
-                "dx12" | "d3d12" => Self::DX12,
-                "metal" | "mtl" => Self::METAL,
-                "opengl" | "gles" | "gl" => Self::GL,
-                "webgpu" => Self::BROWSER_WEBGPU,
-                b => {
-                    log::warn!("unknown backend string '{}'", b);
+            backends |= match Self::from_backend_name(backend.trim()) {
+                Some(backend) => backend,
+                None => {
+                    log::warn!("unknown backend string '{}'", backend.trim());
                     continue;
                 }
             }
@@ -244,6 +422,99 @@ impl Backends {
 
         backends
     }
+
+    /// Returns true if the bit for `backend` is set.
+    ///
+    /// [`Backend::Empty`] has no corresponding flag, so it is never contained.
+    #[must_use]
+    pub const fn contains_backend(&self, backend: Backend) -> bool {
+        self.bits() & (1 << backend as u32) != 0
+    }
+
+    /// Returns an iterator over each individual [`Backend`] whose bit is set, in order of
+    /// their discriminants.
+    pub fn iter_backends(self) -> impl Iterator<Item = Backend> {
+        [
+            Backend::Vulkan,
+            Backend::Metal,
+            Backend::Dx12,
+            Backend::Gl,
+            Backend::BrowserWebGpu,
+        ]
+        .into_iter()
+        .filter(move |&backend| self.contains_backend(backend))
+    }
+
+    /// Maps a single lowercase backend name, as accepted by [`Self::from_comma_list()`], to its backend.
+    fn from_backend_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "vulkan" | "vk" => Self::VULKAN,
+            "dx12" | "d3d12" => Self::DX12,
+            "metal" | "mtl" => Self::METAL,
+            "opengl" | "gles" | "gl" => Self::GL,
+            "webgpu" => Self::BROWSER_WEBGPU,
+            "primary" => Self::PRIMARY,
+            "secondary" => Self::SECONDARY,
+            _ => return None,
+        })
+    }
+}
+
+impl core::str::FromStr for Backends {
+    type Err = ParseBackendError;
+
+    /// Parses a comma separated list of backend names.
+    ///
+    /// Accepts the same names as [`Backends::from_comma_list()`], but fails on unknown names
+    /// instead of ignoring them. Empty entries are skipped, so an empty string parses as
+    /// [`Backends::empty()`].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut backends = Self::empty();
+        for backend in s.to_lowercase().split(',') {
+            let backend = backend.trim();
+            if backend.is_empty() {
+                continue;
+            }
+            backends |= Self::from_backend_name(backend).ok_or(ParseBackendError)?;
+        }
+        Ok(backends)
+    }
+}
+
+#[test]
+fn backends_from_str() {
+    assert_eq!("vulkan".parse(), Ok(Backend::Vulkan));
+    assert_eq!("vk".parse::<Backend>(), Err(ParseBackendError));
+    for backend in [Backend::Vulkan, Backend::Metal, Backend::Dx12, Backend::Gl] {
+        assert_eq!(backend.to_str().parse(), Ok(backend));
+    }
+
+    assert_eq!(
+        " VK, dx12 ,vulkan".parse(),
+        Ok(Backends::VULKAN | Backends::DX12)
+    );
+    assert_eq!(
+        "primary, secondary".parse(),
+        Ok(Backends::PRIMARY | Backends::SECONDARY)
+    );
+    assert_eq!("".parse(), Ok(Backends::empty()));
+    assert_eq!("gl,dx11".parse::<Backends>(), Err(ParseBackendError));
+}
+
+#[test]
+fn backends_iter_backends() {
+    let backends = Backends::VULKAN | Backends::GL;
+    assert!(backends.contains_backend(Backend::Gl));
+    assert!(!backends.contains_backend(Backend::Metal));
+    assert!(!Backends::all().contains_backend(Backend::Empty));
+    assert!(backends.iter_backends().eq([Backend::Vulkan, Backend::Gl]));
+    for backend in Backends::all().iter_backends() {
+        assert_eq!(
+            Backends::all().contains_backend(backend),
+            Backends::all().contains(Backends::from(backend))
+        );
+    }
+    assert_eq!(Backends::all().iter_backends().count(), 5);
 }
 
 /// Options for requesting adapter.
@@ -287,6 +558,16 @@ bitflags::bitflags! {
     /// the feature. If the adapter does not support the feature, requesting a device with it enabled
     /// will panic.
     ///
+    /// Individual features can be looked up by the name of their constant with
+    /// [`Features::from_name`], and the features in a set can be listed by name with
+    /// [`Features::iter_names`], for example when logging or reading a configuration file.
+    /// [`str::parse`] accepts a `|` separated list of such names.
+    ///
+    /// The bit values of the features are checked by tests, so they are not renumbered by
+    /// accident, but features may still be added, removed or renumbered between releases of
+    /// wgpu. To persist a set of features across versions, store the feature names rather than
+    /// [`Features::bits`]. Human-readable serde formats already serialize the names.
+    ///
     /// Corresponds to [WebGPU `GPUFeatureName`](
     /// https://gpuweb.github.io/gpuweb/#enumdef-gpufeaturename).
     #[repr(transparent)]
@@ -348,7 +629,7 @@ bitflags::bitflags! {
         /// Allows the 3d dimension for textures with BC compressed formats.
         ///
         /// This feature must be used in combination with TEXTURE_COMPRESSION_BC to enable 3D textures with BC compression.
-        /// It does not enable the BC formats by itself.
+        /// It does not enable the BC formats by itself, see [`Features::with_dependencies`].
         ///
         /// Supported Platforms:
         /// - desktops
@@ -578,7 +859,7 @@ bitflags::bitflags! {
         const PIPELINE_STATISTICS_QUERY = 1 << 23;
         /// Allows for timestamp queries directly on command encoders.
         ///
-        /// Implies [`Features::TIMESTAMP_QUERY`] is supported.
+        /// Implies [`Features::TIMESTAMP_QUERY`] is supported, see [`Features::with_dependencies`].
         ///
         /// Additionally allows for timestamp writes on command encoders
         /// using  [`CommandEncoder::write_timestamp`].
@@ -592,7 +873,8 @@ bitflags::bitflags! {
         const TIMESTAMP_QUERY_INSIDE_ENCODERS = 1 << 24;
         /// Allows for timestamp queries directly on command encoders.
         ///
-        /// Implies [`Features::TIMESTAMP_QUERY`] & [`Features::TIMESTAMP_QUERY_INSIDE_ENCODERS`] is supported.
+        /// Implies [`Features::TIMESTAMP_QUERY`] & [`Features::TIMESTAMP_QUERY_INSIDE_ENCODERS`] is supported,
+        /// see [`Features::with_dependencies`].
         ///
         /// Additionally allows for timestamp queries to be used inside render & compute passes using:
         /// - [`RenderPass::write_timestamp`]
@@ -929,6 +1211,8 @@ bitflags::bitflags! {
         ///
         /// Allows for the creation of ray-tracing queries within shaders.
         ///
+        /// Requires [`Features::EXPERIMENTAL_RAY_TRACING_ACCELERATION_STRUCTURE`], see [`Features::with_dependencies`].
+        ///
         /// Supported platforms:
         /// - Vulkan
         ///
@@ -1010,6 +1294,8 @@ bitflags::bitflags! {
         const SUBGROUP_VERTEX = 1 << 57;
         /// Allows shaders to use the subgroup barrier
         ///
+        /// Requires [`Features::SUBGROUP`], see [`Features::with_dependencies`].
+        ///
         /// Supported Platforms:
         /// - Vulkan
         /// - Metal
@@ -1084,15 +1370,412 @@ impl Features {
         Self::from_bits_truncate(!Self::all_webgpu_mask().bits())
     }
 
+    /// Features needed to use ray-tracing: both
+    /// [`Features::EXPERIMENTAL_RAY_TRACING_ACCELERATION_STRUCTURE`] and
+    /// [`Features::EXPERIMENTAL_RAY_QUERY`].
+    ///
+    /// This is not a flag of its own, so it does not show up when iterating over feature names.
+    pub const RAY_TRACING: Self =
+        Self::EXPERIMENTAL_RAY_TRACING_ACCELERATION_STRUCTURE.union(Self::EXPERIMENTAL_RAY_QUERY);
+
+    /// Returns true if all features in [`Features::RAY_TRACING`] are enabled.
+    #[must_use]
+    pub const fn supports_ray_tracing(&self) -> bool {
+        self.contains(Self::RAY_TRACING)
+    }
+
+    /// Returns the category of a single feature.
+    ///
+    /// Returns `None` if self is empty or contains more than one feature, even if all of them
+    /// are in the same category.
+    #[must_use]
+    pub fn category(&self) -> Option<FeatureCategory> {
+        if self.bits().count_ones() != 1 {
+            return None;
+        }
+        FeatureCategory::ALL
+            .into_iter()
+            .find(|category| category.features().contains(*self))
+    }
+
     /// Vertex formats allowed for creating and building BLASes
+    ///
+    /// With [`Features::EXPERIMENTAL_RAY_TRACING_ACCELERATION_STRUCTURE`], these are the
+    /// formats that both Vulkan and DX12 are required to support. Two component formats
+    /// use a Z coordinate of zero, and the fourth component of four component formats is
+    /// ignored. Additional formats supported by a particular adapter are not reported.
     #[must_use]
     pub fn allowed_vertex_formats_for_blas(&self) -> Vec<VertexFormat> {
         let mut formats = Vec::new();
         if self.contains(Self::EXPERIMENTAL_RAY_TRACING_ACCELERATION_STRUCTURE) {
-            formats.push(VertexFormat::Float32x3);
+            formats.extend([
+                VertexFormat::Float32x3,
+                VertexFormat::Float32x2,
+                VertexFormat::Float16x2,
+                VertexFormat::Float16x4,
+                VertexFormat::Snorm16x2,
+                VertexFormat::Snorm16x4,
+            ]);
         }
         formats
     }
+
+    /// Pairs of a feature and the features it depends on.
+    ///
+    /// Dependencies may themselves have dependencies, which are resolved by
+    /// [`Features::with_dependencies`].
+    const DEPENDENCIES: [(Self, Self); 5] = [
+        (
+            Self::TEXTURE_COMPRESSION_BC_SLICED_3D,
+            Self::TEXTURE_COMPRESSION_BC,
+        ),
+        (Self::TIMESTAMP_QUERY_INSIDE_ENCODERS, Self::TIMESTAMP_QUERY),
+        (
+            Self::TIMESTAMP_QUERY_INSIDE_PASSES,
+            Self::TIMESTAMP_QUERY_INSIDE_ENCODERS,
+        ),
+        (
+            Self::EXPERIMENTAL_RAY_QUERY,
+            Self::EXPERIMENTAL_RAY_TRACING_ACCELERATION_STRUCTURE,
+        ),
+        (Self::SUBGROUP_BARRIER, Self::SUBGROUP),
+    ];
+
+    /// Returns the features with all features they transitively depend on added.
+    ///
+    /// For example, [`Features::TIMESTAMP_QUERY_INSIDE_PASSES`] depends on
+    /// [`Features::TIMESTAMP_QUERY_INSIDE_ENCODERS`], which in turn depends on
+    /// [`Features::TIMESTAMP_QUERY`], so all three are returned.
+    #[must_use]
+    pub fn with_dependencies(self) -> Self {
+        let mut features = self;
+        loop {
+            let mut expanded = features;
+            for (feature, dependencies) in Self::DEPENDENCIES {
+                if expanded.contains(feature) {
+                    expanded |= dependencies;
+                }
+            }
+            if expanded == features {
+                return features;
+            }
+            features = expanded;
+        }
+    }
+
+    /// Returns the features which are depended on by `self`, but are not part of it.
+    ///
+    /// A device request for features with missing dependencies will fail, unless
+    /// [`Features::with_dependencies`] is used to add them.
+    #[must_use]
+    pub fn missing_dependencies(self) -> Self {
+        self.with_dependencies().difference(self)
+    }
+
+    /// Returns the names of the features in `self` which are not in `supported`.
+    ///
+    /// The names are the same as accepted by [`Features::from_name`]. This is useful for
+    /// reporting exactly which features are missing when requesting a device fails.
+    #[must_use]
+    pub fn missing_from(&self, supported: Self) -> Vec<&'static str> {
+        self.difference(supported)
+            .iter_names()
+            .map(|(name, _)| name)
+            .collect()
+    }
+}
+
+/// Error returned when a string does not name a valid set of [`Features`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ParseFeaturesError;
+
+impl core::fmt::Display for ParseFeaturesError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("invalid feature name")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseFeaturesError {}
+
+impl core::str::FromStr for Features {
+    type Err = ParseFeaturesError;
+
+    /// Parses a `|` separated list of feature names, as accepted by [`Features::from_name`].
+    ///
+    /// Whitespace around names is ignored, and an empty string parses as [`Features::empty()`].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut features = Self::empty();
+        for name in s.split('|') {
+            let name = name.trim();
+            if name.is_empty() {
+                continue;
+            }
+            features |= Self::from_name(name).ok_or(ParseFeaturesError)?;
+        }
+        Ok(features)
+    }
+}
+
+#[test]
+fn features_names() {
+    assert_eq!(
+        Features::from_name("TEXTURE_COMPRESSION_BC"),
+        Some(Features::TEXTURE_COMPRESSION_BC)
+    );
+    assert_eq!(Features::from_name("texture_compression_bc"), None);
+
+    let features = Features::DEPTH_CLIP_CONTROL | Features::SHADER_F16;
+    let names: Vec<_> = features.iter_names().map(|(name, _)| name).collect();
+    assert_eq!(names, ["DEPTH_CLIP_CONTROL", "SHADER_F16"]);
+    assert_eq!(names.join(" | ").parse(), Ok(features));
+
+    assert_eq!("".parse(), Ok(Features::empty()));
+    assert_eq!(
+        "SHADER_F16 | NOT_A_FEATURE".parse::<Features>(),
+        Err(ParseFeaturesError)
+    );
+
+    assert_eq!(
+        features.missing_from(Features::SHADER_F16),
+        ["DEPTH_CLIP_CONTROL"]
+    );
+    assert!(features.missing_from(Features::all()).is_empty());
+}
+
+#[test]
+fn features_bits() {
+    // The bits are used when serializing to non-human-readable formats, so catch accidental
+    // renumbering.
+    assert_eq!(Features::DEPTH_CLIP_CONTROL.bits(), 1);
+    assert_eq!(Features::TEXTURE_COMPRESSION_BC.bits(), 1 << 2);
+    assert_eq!(Features::TIMESTAMP_QUERY.bits(), 1 << 6);
+    assert_eq!(Features::SHADER_F16.bits(), 1 << 8);
+    assert_eq!(Features::PUSH_CONSTANTS.bits(), 1 << 35);
+    assert_eq!(Features::MULTIVIEW.bits(), 1 << 44);
+
+    for (_, feature) in Features::all().iter_names() {
+        assert_eq!(feature.bits().count_ones(), 1);
+        assert_eq!(Features::from_bits_retain(feature.bits()), feature);
+    }
+    assert_eq!(
+        Features::from_bits_retain(Features::all().bits()),
+        Features::all()
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn features_serde_round_trip() {
+    for features in [
+        Features::empty(),
+        Features::DEPTH_CLIP_CONTROL | Features::MULTIVIEW,
+        Features::all(),
+    ] {
+        let json = serde_json::to_string(&features).unwrap();
+        assert_eq!(serde_json::from_str::<Features>(&json).unwrap(), features);
+    }
+}
+
+/// Category of a feature, as returned by [`Features::category`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FeatureCategory {
+    /// Features enabling texture formats, or additional capabilities of them.
+    TextureFormat,
+    /// Features enabling shader capabilities.
+    Shader,
+    /// Features enabling API capabilities.
+    Api,
+    /// Features enabling query types or places to write queries.
+    Query,
+    /// Features enabling ray-tracing.
+    RayTracing,
+    /// Features enabling binding arrays and indexing into them.
+    BindingArray,
+}
+
+impl FeatureCategory {
+    /// All categories.
+    pub const ALL: [Self; 6] = [
+        Self::TextureFormat,
+        Self::Shader,
+        Self::Api,
+        Self::Query,
+        Self::RayTracing,
+        Self::BindingArray,
+    ];
+
+    /// Returns all features in this category.
+    #[must_use]
+    pub const fn features(self) -> Features {
+        match self {
+            Self::TextureFormat => Features::DEPTH32FLOAT_STENCIL8
+                .union(Features::TEXTURE_COMPRESSION_BC)
+                .union(Features::TEXTURE_COMPRESSION_BC_SLICED_3D)
+                .union(Features::TEXTURE_COMPRESSION_ETC2)
+                .union(Features::TEXTURE_COMPRESSION_ASTC)
+                .union(Features::RG11B10UFLOAT_RENDERABLE)
+                .union(Features::BGRA8UNORM_STORAGE)
+                .union(Features::FLOAT32_FILTERABLE)
+                .union(Features::TEXTURE_FORMAT_16BIT_NORM)
+                .union(Features::TEXTURE_COMPRESSION_ASTC_HDR)
+                .union(Features::TEXTURE_ADAPTER_SPECIFIC_FORMAT_FEATURES)
+                .union(Features::TEXTURE_FORMAT_NV12),
+            Self::Shader => Features::SHADER_F16
+                .union(Features::TEXTURE_INT64_ATOMIC)
+                .union(Features::SHADER_FLOAT32_ATOMIC)
+                .union(Features::SPIRV_SHADER_PASSTHROUGH)
+                .union(Features::TEXTURE_ATOMIC)
+                .union(Features::SHADER_F64)
+                .union(Features::SHADER_I16)
+                .union(Features::SHADER_PRIMITIVE_INDEX)
+                .union(Features::SHADER_EARLY_DEPTH_TEST)
+                .union(Features::DUAL_SOURCE_BLENDING)
+                .union(Features::SHADER_INT64)
+                .union(Features::SUBGROUP)
+                .union(Features::SUBGROUP_VERTEX)
+                .union(Features::SUBGROUP_BARRIER)
+                .union(Features::SHADER_INT64_ATOMIC_MIN_MAX)
+                .union(Features::SHADER_INT64_ATOMIC_ALL_OPS),
+            Self::Api => Features::DEPTH_CLIP_CONTROL
+                .union(Features::INDIRECT_FIRST_INSTANCE)
+                .union(Features::MAPPABLE_PRIMARY_BUFFERS)
+                .union(Features::MULTI_DRAW_INDIRECT)
+                .union(Features::MULTI_DRAW_INDIRECT_COUNT)
+                .union(Features::PUSH_CONSTANTS)
+                .union(Features::ADDRESS_MODE_CLAMP_TO_ZERO)
+                .union(Features::ADDRESS_MODE_CLAMP_TO_BORDER)
+                .union(Features::POLYGON_MODE_LINE)
+                .union(Features::POLYGON_MODE_POINT)
+                .union(Features::CONSERVATIVE_RASTERIZATION)
+                .union(Features::VERTEX_WRITABLE_STORAGE)
+                .union(Features::CLEAR_TEXTURE)
+                .union(Features::MULTIVIEW)
+                .union(Features::VERTEX_ATTRIBUTE_64BIT)
+                .union(Features::PIPELINE_CACHE)
+                .union(Features::VULKAN_GOOGLE_DISPLAY_TIMING)
+                .union(Features::VULKAN_EXTERNAL_MEMORY_WIN32),
+            Self::Query => Features::TIMESTAMP_QUERY
+                .union(Features::PIPELINE_STATISTICS_QUERY)
+                .union(Features::TIMESTAMP_QUERY_INSIDE_ENCODERS)
+                .union(Features::TIMESTAMP_QUERY_INSIDE_PASSES),
+            Self::RayTracing => Features::RAY_TRACING,
+            Self::BindingArray => Features::TEXTURE_BINDING_ARRAY
+                .union(Features::BUFFER_BINDING_ARRAY)
+                .union(Features::STORAGE_RESOURCE_BINDING_ARRAY)
+                .union(Features::SAMPLED_TEXTURE_AND_STORAGE_BUFFER_ARRAY_NON_UNIFORM_INDEXING)
+                .union(Features::UNIFORM_BUFFER_AND_STORAGE_TEXTURE_ARRAY_NON_UNIFORM_INDEXING)
+                .union(Features::PARTIALLY_BOUND_BINDING_ARRAY),
+        }
+    }
+}
+
+#[test]
+fn features_category() {
+    // Every feature is in exactly one category.
+    for feature in Features::all().iter() {
+        let categories = FeatureCategory::ALL
+            .into_iter()
+            .filter(|category| category.features().contains(feature));
+        assert_eq!(categories.count(), 1, "{feature:?}");
+        assert!(feature.category().is_some());
+    }
+
+    assert_eq!(
+        Features::SHADER_F16.category(),
+        Some(FeatureCategory::Shader)
+    );
+    assert_eq!(
+        Features::EXPERIMENTAL_RAY_QUERY.category(),
+        Some(FeatureCategory::RayTracing)
+    );
+    assert_eq!(Features::RAY_TRACING.category(), None);
+    assert_eq!(Features::empty().category(), None);
+}
+
+#[test]
+fn features_ray_tracing() {
+    assert!(Features::RAY_TRACING.supports_ray_tracing());
+    assert!(!Features::EXPERIMENTAL_RAY_QUERY.supports_ray_tracing());
+    assert!(!Features::EXPERIMENTAL_RAY_TRACING_ACCELERATION_STRUCTURE.supports_ray_tracing());
+    assert_eq!(Features::RAY_TRACING.iter_names().count(), 2);
+
+    assert!(Features::empty()
+        .allowed_vertex_formats_for_blas()
+        .is_empty());
+    let formats = Features::RAY_TRACING.allowed_vertex_formats_for_blas();
+    assert!(formats.contains(&VertexFormat::Float32x3));
+    assert!(formats.contains(&VertexFormat::Snorm16x4));
+    assert!(!formats.contains(&VertexFormat::Float32x4));
+}
+
+#[test]
+fn features_dependencies() {
+    let timestamps = Features::TIMESTAMP_QUERY
+        | Features::TIMESTAMP_QUERY_INSIDE_ENCODERS
+        | Features::TIMESTAMP_QUERY_INSIDE_PASSES;
+    assert_eq!(
+        Features::TIMESTAMP_QUERY_INSIDE_PASSES.with_dependencies(),
+        timestamps
+    );
+    assert_eq!(
+        Features::TIMESTAMP_QUERY_INSIDE_PASSES.missing_dependencies(),
+        Features::TIMESTAMP_QUERY | Features::TIMESTAMP_QUERY_INSIDE_ENCODERS
+    );
+    assert_eq!(timestamps.missing_dependencies(), Features::empty());
+    assert_eq!(
+        Features::SUBGROUP_BARRIER.missing_dependencies(),
+        Features::SUBGROUP
+    );
+    assert_eq!(
+        Features::SHADER_F16.with_dependencies(),
+        Features::SHADER_F16
+    );
+}
+
+/// Invokes `$macro!(field, Ordering)` for every field of [`Limits`], in declaration order.
+///
+/// `Ordering` is `Less` for the `max_*` limits, where higher values are "better", and `Greater`
+/// for the `min_*` limits, where lower values are "better". This is the ordering a requested
+/// limit must have relative to the allowed limit, as checked by
+/// [`Limits::check_limits_with_fail_fn`].
+macro_rules! with_limits {
+    ($macro:ident) => {
+        $macro!(max_texture_dimension_1d, Less);
+        $macro!(max_texture_dimension_2d, Less);
+        $macro!(max_texture_dimension_3d, Less);
+        $macro!(max_texture_array_layers, Less);
+        $macro!(max_bind_groups, Less);
+        $macro!(max_bindings_per_bind_group, Less);
+        $macro!(max_dynamic_uniform_buffers_per_pipeline_layout, Less);
+        $macro!(max_dynamic_storage_buffers_per_pipeline_layout, Less);
+        $macro!(max_sampled_textures_per_shader_stage, Less);
+        $macro!(max_samplers_per_shader_stage, Less);
+        $macro!(max_storage_buffers_per_shader_stage, Less);
+        $macro!(max_storage_textures_per_shader_stage, Less);
+        $macro!(max_uniform_buffers_per_shader_stage, Less);
+        $macro!(max_uniform_buffer_binding_size, Less);
+        $macro!(max_storage_buffer_binding_size, Less);
+        $macro!(max_vertex_buffers, Less);
+        $macro!(max_buffer_size, Less);
+        $macro!(max_vertex_attributes, Less);
+        $macro!(max_vertex_buffer_array_stride, Less);
+        $macro!(min_uniform_buffer_offset_alignment, Greater);
+        $macro!(min_storage_buffer_offset_alignment, Greater);
+        $macro!(max_inter_stage_shader_components, Less);
+        $macro!(max_color_attachments, Less);
+        $macro!(max_color_attachment_bytes_per_sample, Less);
+        $macro!(max_compute_workgroup_storage_size, Less);
+        $macro!(max_compute_invocations_per_workgroup, Less);
+        $macro!(max_compute_workgroup_size_x, Less);
+        $macro!(max_compute_workgroup_size_y, Less);
+        $macro!(max_compute_workgroup_size_z, Less);
+        $macro!(max_compute_workgroups_per_dimension, Less);
+        $macro!(min_subgroup_size, Greater);
+        $macro!(max_subgroup_size, Less);
+        $macro!(max_push_constant_size, Less);
+        $macro!(max_non_sampler_bindings, Less);
+    };
 }
 
 /// Represents the sets of limits an adapter/device supports.
@@ -1419,6 +2102,19 @@ impl Limits {
         }
     }
 
+    /// Returns the limits of the given preset tier.
+    ///
+    /// This dispatches to [`Limits::default`], [`Limits::downlevel_defaults`] or
+    /// [`Limits::downlevel_webgl2_defaults`].
+    #[must_use]
+    pub const fn preset(preset: LimitsPreset) -> Self {
+        match preset {
+            LimitsPreset::WebGpu => Self::defaults(),
+            LimitsPreset::Downlevel => Self::downlevel_defaults(),
+            LimitsPreset::DownlevelWebGl2 => Self::downlevel_webgl2_defaults(),
+        }
+    }
+
     /// Modify the current limits to use the resolution limits of the other.
     ///
     /// This is useful because the swapchain might need to be larger than any other image in the application.
@@ -1446,88 +2142,732 @@ impl Limits {
         }
     }
 
-    /// Compares every limits within self is within the limits given in `allowed`.
+    /// Modify the current limits to use the stricter of the two buffer alignment limits.
     ///
-    /// If you need detailed information on failures, look at [`Limits::check_limits_with_fail_fn`].
+    /// Unlike [`Limits::using_alignment`], this never loosens the alignment of `self`.
+    /// Lower alignments are "better", so the larger of the two values is kept to be safe.
+    /// This is useful when code was written assuming a given alignment, and the adapter's
+    /// alignment should only be taken into account if it is stricter.
     #[must_use]
-    pub fn check_limits(&self, allowed: &Self) -> bool {
-        let mut within = true;
-        self.check_limits_with_fail_fn(allowed, true, |_, _, _| within = false);
-        within
+    pub const fn using_min_alignment(self, other: Self) -> Self {
+        const fn stricter(a: u32, b: u32) -> u32 {
+            if a > b {
+                a
+            } else {
+                b
+            }
+        }
+
+        Self {
+            min_uniform_buffer_offset_alignment: stricter(
+                self.min_uniform_buffer_offset_alignment,
+                other.min_uniform_buffer_offset_alignment,
+            ),
+            min_storage_buffer_offset_alignment: stricter(
+                self.min_storage_buffer_offset_alignment,
+                other.min_storage_buffer_offset_alignment,
+            ),
+            ..self
+        }
     }
 
-    /// Compares every limits within self is within the limits given in `allowed`.
-    /// For an easy to use binary choice, use [`Limits::check_limits`].
+    /// Returns true if neither subgroup size is zero, which is the case when the backend does
+    /// not report them.
+    const fn has_subgroup_sizes(&self) -> bool {
+        self.min_subgroup_size > 0 && self.max_subgroup_size > 0
+    }
+
+    /// Combines two sets of limits, taking the "better" value of each limit.
     ///
-    /// If a value is not within the allowed limit, this function calls the `fail_fn`
-    /// with the:
-    ///  - limit name
-    ///  - self's limit
-    ///  - allowed's limit.
+    /// This is the most restrictive set of limits within which both `self` and `other` are,
+    /// as checked by [`Limits::check_limits`]. It is useful to merge the requirements of
+    /// multiple parts of an application into a single set of limits to request.
     ///
-    /// If fatal is true, a single failure bails out the comparison after a single failure.
-    pub fn check_limits_with_fail_fn(
-        &self,
-        allowed: &Self,
-        fatal: bool,
-        mut fail_fn: impl FnMut(&'static str, u64, u64),
-    ) {
-        use core::cmp::Ordering;
+    /// The subgroup sizes are combined as a pair: if only one side reports them, its sizes
+    /// are taken as they are.
+    #[must_use]
+    pub fn max(self, other: Self) -> Self {
+        let mut limits = self;
+
+        macro_rules! better {
+            (min_subgroup_size, $ordering:ident) => {
+                if !other.has_subgroup_sizes() {
+                } else if !limits.has_subgroup_sizes() {
+                    limits.min_subgroup_size = other.min_subgroup_size;
+                    limits.max_subgroup_size = other.max_subgroup_size;
+                } else {
+                    limits.min_subgroup_size =
+                        limits.min_subgroup_size.min(other.min_subgroup_size);
+                    limits.max_subgroup_size =
+                        limits.max_subgroup_size.max(other.max_subgroup_size);
+                }
+            };
+            // Merged together with `min_subgroup_size`.
+            (max_subgroup_size, $ordering:ident) => {};
+            ($name:ident, Less) => {
+                limits.$name = limits.$name.max(other.$name);
+            };
+            ($name:ident, Greater) => {
+                limits.$name = limits.$name.min(other.$name);
+            };
+        }
 
-        macro_rules! compare {
-            ($name:ident, $ordering:ident) => {
-                match self.$name.cmp(&allowed.$name) {
-                    Ordering::$ordering | Ordering::Equal => (),
-                    _ => {
-                        fail_fn(stringify!($name), self.$name as u64, allowed.$name as u64);
-                        if fatal {
-                            return;
-                        }
-                    }
+        with_limits!(better);
+        limits
+    }
+
+    /// Combines two sets of limits, taking the "worse" value of each limit.
+    ///
+    /// This is the least restrictive set of limits which is within both `self` and `other`,
+    /// as checked by [`Limits::check_limits`]. It is useful to find the limits supported by
+    /// multiple adapters.
+    ///
+    /// The subgroup sizes are combined as a pair: if only one side reports them, its sizes
+    /// are taken as they are.
+    #[must_use]
+    pub fn min(self, other: Self) -> Self {
+        let mut limits = self;
+
+        macro_rules! worse {
+            (min_subgroup_size, $ordering:ident) => {
+                if !other.has_subgroup_sizes() {
+                } else if !limits.has_subgroup_sizes() {
+                    limits.min_subgroup_size = other.min_subgroup_size;
+                    limits.max_subgroup_size = other.max_subgroup_size;
+                } else {
+                    limits.min_subgroup_size =
+                        limits.min_subgroup_size.max(other.min_subgroup_size);
+                    limits.max_subgroup_size =
+                        limits.max_subgroup_size.min(other.max_subgroup_size);
                 }
             };
+            // Merged together with `min_subgroup_size`.
+            (max_subgroup_size, $ordering:ident) => {};
+            ($name:ident, Less) => {
+                limits.$name = limits.$name.min(other.$name);
+            };
+            ($name:ident, Greater) => {
+                limits.$name = limits.$name.max(other.$name);
+            };
         }
 
-        compare!(max_texture_dimension_1d, Less);
-        compare!(max_texture_dimension_2d, Less);
-        compare!(max_texture_dimension_3d, Less);
-        compare!(max_texture_array_layers, Less);
-        compare!(max_bind_groups, Less);
-        compare!(max_bindings_per_bind_group, Less);
-        compare!(max_dynamic_uniform_buffers_per_pipeline_layout, Less);
-        compare!(max_dynamic_storage_buffers_per_pipeline_layout, Less);
-        compare!(max_sampled_textures_per_shader_stage, Less);
-        compare!(max_samplers_per_shader_stage, Less);
-        compare!(max_storage_buffers_per_shader_stage, Less);
-        compare!(max_storage_textures_per_shader_stage, Less);
-        compare!(max_uniform_buffers_per_shader_stage, Less);
-        compare!(max_uniform_buffer_binding_size, Less);
-        compare!(max_storage_buffer_binding_size, Less);
-        compare!(max_vertex_buffers, Less);
-        compare!(max_buffer_size, Less);
-        compare!(max_vertex_attributes, Less);
-        compare!(max_vertex_buffer_array_stride, Less);
-        compare!(min_uniform_buffer_offset_alignment, Greater);
-        compare!(min_storage_buffer_offset_alignment, Greater);
-        compare!(max_inter_stage_shader_components, Less);
-        compare!(max_color_attachments, Less);
-        compare!(max_color_attachment_bytes_per_sample, Less);
-        compare!(max_compute_workgroup_storage_size, Less);
-        compare!(max_compute_invocations_per_workgroup, Less);
-        compare!(max_compute_workgroup_size_x, Less);
-        compare!(max_compute_workgroup_size_y, Less);
-        compare!(max_compute_workgroup_size_z, Less);
-        compare!(max_compute_workgroups_per_dimension, Less);
-        if self.min_subgroup_size > 0 && self.max_subgroup_size > 0 {
-            compare!(min_subgroup_size, Greater);
-            compare!(max_subgroup_size, Less);
-        }
-        compare!(max_push_constant_size, Less);
-        compare!(max_non_sampler_bindings, Less);
+        with_limits!(worse);
+        limits
     }
-}
 
-/// Represents the sets of additional limits on an adapter,
+    /// Clamps the limits to those supported by an adapter, taking the minimum of the
+    /// "higher is better" limits and the maximum of the "lower is better" ones, such as the
+    /// alignments.
+    ///
+    /// The result always passes [`Limits::check_limits`] against `adapter`, so it can be used to
+    /// request the best available limits without failing device creation. This is the same as
+    /// [`Limits::min`] with the adapter limits, except that the subgroup sizes of `self` are
+    /// kept if either side does not report them.
+    #[must_use]
+    pub fn clamp_to(self, adapter: &Self) -> Self {
+        let keep_subgroup_sizes = !self.has_subgroup_sizes() || !adapter.has_subgroup_sizes();
+        let (min_subgroup_size, max_subgroup_size) =
+            (self.min_subgroup_size, self.max_subgroup_size);
+
+        let mut limits = self.min(adapter.clone());
+        if keep_subgroup_sizes {
+            limits.min_subgroup_size = min_subgroup_size;
+            limits.max_subgroup_size = max_subgroup_size;
+        }
+        limits
+    }
+
+    /// Returns every limit as a pair of its field name and its value.
+    ///
+    /// The limits are yielded in the same order, and with the same names, as reported by
+    /// [`Limits::check_limits_with_fail_fn`].
+    pub fn fields(&self) -> impl Iterator<Item = (&'static str, u64)> {
+        const LEN: usize = {
+            let mut len = 0;
+
+            macro_rules! count {
+                ($name:ident, $ordering:ident) => {
+                    len += 1;
+                };
+            }
+
+            with_limits!(count);
+            len
+        };
+
+        let mut fields = [("", 0); LEN];
+        let mut index = 0;
+
+        macro_rules! field {
+            ($name:ident, $ordering:ident) => {
+                fields[index] = (stringify!($name), self.$name as u64);
+                index += 1;
+            };
+        }
+
+        with_limits!(field);
+        debug_assert_eq!(index, LEN);
+        fields.into_iter()
+    }
+
+    /// Compares every limits within self is within the limits given in `allowed`.
+    ///
+    /// If you need detailed information on failures, look at [`Limits::check_limits_with_fail_fn`].
+    #[must_use]
+    pub fn check_limits(&self, allowed: &Self) -> bool {
+        let mut within = true;
+        self.check_limits_with_fail_fn(allowed, true, |_, _, _| within = false);
+        within
+    }
+
+    /// Compares every limits within self is within the limits given in `allowed`,
+    /// returning every limit that is not.
+    ///
+    /// An empty list means that [`Limits::check_limits`] would return true.
+    #[must_use]
+    pub fn check_limits_report(&self, allowed: &Self) -> Vec<LimitViolation> {
+        let mut violations = Vec::new();
+        self.check_limits_with_fail_fn(allowed, false, |name, requested, allowed| {
+            violations.push(LimitViolation {
+                name,
+                requested,
+                allowed,
+            });
+        });
+        violations
+    }
+
+    /// Counts the limits within self that are not within the limits given in `allowed`.
+    ///
+    /// This can be used to find which of several limits, such as the [`LimitsPreset`]s, is
+    /// closest to supporting the limits in self. Zero means that [`Limits::check_limits`]
+    /// would return true.
+    #[must_use]
+    pub fn num_violations(&self, allowed: &Self) -> usize {
+        let mut count = 0;
+        self.check_limits_with_fail_fn(allowed, false, |_, _, _| count += 1);
+        count
+    }
+
+    /// Compares every limits within self is within the limits given in `allowed`.
+    /// For an easy to use binary choice, use [`Limits::check_limits`].
+    ///
+    /// If a value is not within the allowed limit, this function calls the `fail_fn`
+    /// with the:
+    ///  - limit name
+    ///  - self's limit
+    ///  - allowed's limit.
+    ///
+    /// If fatal is true, a single failure bails out the comparison after a single failure.
+    ///
+    /// The subgroup sizes are only compared if both `self` and `allowed` report them, that is
+    /// if neither of them has a subgroup size of zero.
+    pub fn check_limits_with_fail_fn(
+        &self,
+        allowed: &Self,
+        fatal: bool,
+        mut fail_fn: impl FnMut(&'static str, u64, u64),
+    ) {
+        use core::cmp::Ordering;
+
+        // Subgroup sizes are only known on some backends, and are zero otherwise.
+        let compare_subgroup_sizes = self.has_subgroup_sizes() && allowed.has_subgroup_sizes();
+
+        macro_rules! compare {
+            (min_subgroup_size, $ordering:ident) => {
+                if compare_subgroup_sizes {
+                    compare!(@compare min_subgroup_size, $ordering);
+                }
+            };
+            (max_subgroup_size, $ordering:ident) => {
+                if compare_subgroup_sizes {
+                    compare!(@compare max_subgroup_size, $ordering);
+                }
+            };
+            ($name:ident, $ordering:ident) => {
+                compare!(@compare $name, $ordering);
+            };
+            (@compare $name:ident, $ordering:ident) => {
+                match self.$name.cmp(&allowed.$name) {
+                    Ordering::$ordering | Ordering::Equal => (),
+                    _ => {
+                        fail_fn(stringify!($name), self.$name as u64, allowed.$name as u64);
+                        if fatal {
+                            return;
+                        }
+                    }
+                }
+            };
+        }
+
+        with_limits!(compare);
+    }
+
+    /// Returns the range of subgroup sizes, from [`Self::min_subgroup_size`] to
+    /// [`Self::max_subgroup_size`] inclusive.
+    ///
+    /// Subgroup sizes are only known on some backends, and are zero otherwise, in which case
+    /// this returns `None`. It also returns `None` if the sizes are not valid, see
+    /// [`Self::is_subgroup_size_valid`].
+    #[must_use]
+    pub fn subgroup_size_range(&self) -> Option<RangeInclusive<u32>> {
+        let (min, max) = (self.min_subgroup_size, self.max_subgroup_size);
+        (min.is_power_of_two() && max.is_power_of_two() && min <= max).then_some(min..=max)
+    }
+
+    /// Returns true if the subgroup sizes are either both zero, as they are when unknown, or
+    /// are both powers of two with [`Self::min_subgroup_size`] not greater than
+    /// [`Self::max_subgroup_size`].
+    #[must_use]
+    pub fn is_subgroup_size_valid(&self) -> bool {
+        (self.min_subgroup_size == 0 && self.max_subgroup_size == 0)
+            || self.subgroup_size_range().is_some()
+    }
+
+    /// Checks the number of bindings used by a single shader stage against the
+    /// `max_*_per_shader_stage` limits, returning every limit that is exceeded.
+    ///
+    /// To validate a pipeline, call this for the bindings visible to each of its stages.
+    pub fn fits_bindings(&self, counts: &BindingCounts) -> Result<(), Vec<LimitViolation>> {
+        let checks = [
+            (
+                "max_sampled_textures_per_shader_stage",
+                counts.sampled_textures,
+                self.max_sampled_textures_per_shader_stage,
+            ),
+            (
+                "max_samplers_per_shader_stage",
+                counts.samplers,
+                self.max_samplers_per_shader_stage,
+            ),
+            (
+                "max_storage_buffers_per_shader_stage",
+                counts.storage_buffers,
+                self.max_storage_buffers_per_shader_stage,
+            ),
+            (
+                "max_storage_textures_per_shader_stage",
+                counts.storage_textures,
+                self.max_storage_textures_per_shader_stage,
+            ),
+            (
+                "max_uniform_buffers_per_shader_stage",
+                counts.uniform_buffers,
+                self.max_uniform_buffers_per_shader_stage,
+            ),
+        ];
+
+        let violations: Vec<_> = checks
+            .into_iter()
+            .filter(|&(_, count, limit)| count > limit)
+            .map(|(name, count, limit)| LimitViolation {
+                name,
+                requested: count.into(),
+                allowed: limit.into(),
+            })
+            .collect();
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(violations)
+        }
+    }
+}
+
+/// Number of bindings of each type used by a single shader stage.
+///
+/// Used with [`Limits::fits_bindings`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct BindingCounts {
+    /// Number of sampled textures.
+    pub sampled_textures: u32,
+    /// Number of samplers.
+    pub samplers: u32,
+    /// Number of storage buffers.
+    pub storage_buffers: u32,
+    /// Number of storage textures.
+    pub storage_textures: u32,
+    /// Number of uniform buffers.
+    pub uniform_buffers: u32,
+}
+
+/// A limit that is not within the allowed limit, as reported by [`Limits::check_limits_report`]
+/// and [`Limits::fits_bindings`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct LimitViolation {
+    /// Name of the limit, matching the field name in [`Limits`].
+    pub name: &'static str,
+    /// The requested value of the limit.
+    pub requested: u64,
+    /// The allowed value of the limit.
+    pub allowed: u64,
+}
+
+impl core::fmt::Display for LimitViolation {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "Limit '{}' value {} is better than allowed {}",
+            self.name, self.requested, self.allowed
+        )
+    }
+}
+
+/// The documented tiers of [`Limits`], as returned by [`Limits::preset`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum LimitsPreset {
+    /// The limits guaranteed by WebGPU, see [`Limits::default`].
+    #[default]
+    #[cfg_attr(feature = "serde", serde(rename = "webgpu"))]
+    WebGpu,
+    /// Limits supported by most downlevel devices, see [`Limits::downlevel_defaults`].
+    Downlevel,
+    /// Limits supported by WebGL2, see [`Limits::downlevel_webgl2_defaults`].
+    #[cfg_attr(feature = "serde", serde(rename = "downlevel-webgl2"))]
+    DownlevelWebGl2,
+}
+
+impl LimitsPreset {
+    /// Returns the name of the preset, as accepted by its [`FromStr`](core::str::FromStr) impl.
+    #[must_use]
+    pub const fn to_str(self) -> &'static str {
+        match self {
+            Self::WebGpu => "webgpu",
+            Self::Downlevel => "downlevel",
+            Self::DownlevelWebGl2 => "downlevel-webgl2",
+        }
+    }
+}
+
+impl core::fmt::Display for LimitsPreset {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.to_str())
+    }
+}
+
+/// Error returned when a string does not name a valid [`LimitsPreset`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ParseLimitsPresetError;
+
+impl core::fmt::Display for ParseLimitsPresetError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("invalid limits preset")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseLimitsPresetError {}
+
+impl core::str::FromStr for LimitsPreset {
+    type Err = ParseLimitsPresetError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "webgpu" => Self::WebGpu,
+            "downlevel" => Self::Downlevel,
+            "downlevel-webgl2" => Self::DownlevelWebGl2,
+            _ => return Err(ParseLimitsPresetError),
+        })
+    }
+}
+
+#[test]
+fn limits_preset() {
+    for preset in [
+        LimitsPreset::WebGpu,
+        LimitsPreset::Downlevel,
+        LimitsPreset::DownlevelWebGl2,
+    ] {
+        assert_eq!(preset.to_str().parse(), Ok(preset));
+    }
+    assert_eq!(Limits::preset(LimitsPreset::WebGpu), Limits::default());
+    assert_eq!(
+        Limits::preset(LimitsPreset::DownlevelWebGl2),
+        Limits::downlevel_webgl2_defaults()
+    );
+    assert_eq!(
+        "webgl2".parse::<LimitsPreset>(),
+        Err(ParseLimitsPresetError)
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn limits_preset_serde_matches_to_str() {
+    use alloc::format;
+
+    for preset in [
+        LimitsPreset::WebGpu,
+        LimitsPreset::Downlevel,
+        LimitsPreset::DownlevelWebGl2,
+    ] {
+        let json = serde_json::to_string(&preset).unwrap();
+        assert_eq!(json, format!("\"{}\"", preset.to_str()));
+        assert_eq!(serde_json::from_str::<LimitsPreset>(&json).unwrap(), preset);
+    }
+}
+
+#[test]
+fn limits_clamp_to() {
+    // A simple LCG, so the test is deterministic.
+    let mut state = 0x1234_5678_u64;
+    let mut next = || {
+        state = state
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1_442_695_040_888_963_407);
+        state >> 33
+    };
+
+    for _ in 0..100 {
+        let mut requested = Limits::default();
+        let mut adapter = Limits::default();
+        macro_rules! randomize {
+            ($name:ident, $ordering:ident) => {
+                requested.$name = (next() % 1024) as _;
+                adapter.$name = (next() % 1024) as _;
+            };
+        }
+        with_limits!(randomize);
+        assert!(requested.clamp_to(&adapter).check_limits(&adapter));
+    }
+
+    let requested = Limits {
+        max_bind_groups: 16,
+        min_uniform_buffer_offset_alignment: 64,
+        ..Limits::default()
+    };
+    let clamped = requested.clamp_to(&Limits::downlevel_defaults());
+    assert_eq!(clamped.max_bind_groups, 4);
+    assert_eq!(clamped.min_uniform_buffer_offset_alignment, 256);
+
+    let adapter = Limits {
+        min_subgroup_size: 4,
+        max_subgroup_size: 128,
+        ..Limits::default()
+    };
+    let clamped = Limits::default().clamp_to(&adapter);
+    assert!(clamped.is_subgroup_size_valid());
+    assert_eq!(clamped.subgroup_size_range(), None);
+    assert!(clamped.check_limits(&adapter));
+
+    let requested = Limits {
+        min_subgroup_size: 2,
+        max_subgroup_size: 64,
+        ..Limits::default()
+    };
+    let clamped = requested.clone().clamp_to(&adapter);
+    assert!(clamped.is_subgroup_size_valid());
+    assert_eq!(clamped.subgroup_size_range(), Some(4..=64));
+    assert!(clamped.check_limits(&adapter));
+
+    let clamped = requested.clamp_to(&Limits::default());
+    assert_eq!(clamped.subgroup_size_range(), Some(2..=64));
+    assert!(clamped.check_limits(&Limits::default()));
+}
+
+#[test]
+fn limits_subgroup_size_range() {
+    let disabled = Limits::default();
+    assert_eq!(disabled.subgroup_size_range(), None);
+    assert!(disabled.is_subgroup_size_valid());
+
+    let limits = Limits {
+        min_subgroup_size: 8,
+        max_subgroup_size: 32,
+        ..Limits::default()
+    };
+    assert_eq!(limits.subgroup_size_range(), Some(8..=32));
+    assert!(limits.is_subgroup_size_valid());
+
+    for (min, max) in [(32, 8), (0, 32), (8, 24)] {
+        let limits = Limits {
+            min_subgroup_size: min,
+            max_subgroup_size: max,
+            ..Limits::default()
+        };
+        assert_eq!(limits.subgroup_size_range(), None);
+        assert!(!limits.is_subgroup_size_valid());
+    }
+}
+
+#[test]
+fn limits_fields() {
+    let allowed = Limits {
+        min_subgroup_size: 4,
+        max_subgroup_size: 128,
+        ..Limits::default()
+    };
+    let mut requested = allowed.clone();
+    macro_rules! violate {
+        ($name:ident, Less) => {
+            requested.$name += 1;
+        };
+        ($name:ident, Greater) => {
+            requested.$name -= 1;
+        };
+    }
+    with_limits!(violate);
+
+    let fields: Vec<_> = requested.fields().collect();
+    assert_eq!(fields.len(), requested.num_violations(&allowed));
+    let names: Vec<_> = requested
+        .check_limits_report(&allowed)
+        .into_iter()
+        .map(|violation| violation.name)
+        .collect();
+    assert!(fields.iter().map(|&(name, _)| name).eq(names));
+    assert!(fields.contains(&("max_bind_groups", 5)));
+    assert!(fields.contains(&("min_uniform_buffer_offset_alignment", 255)));
+}
+
+#[test]
+fn limits_num_violations() {
+    let downlevel = Limits::downlevel_defaults();
+    let requested = Limits {
+        max_texture_dimension_2d: downlevel.max_texture_dimension_2d * 2,
+        // Alignments are violated by being smaller.
+        min_uniform_buffer_offset_alignment: downlevel.min_uniform_buffer_offset_alignment / 2,
+        ..downlevel.clone()
+    };
+    assert_eq!(requested.num_violations(&downlevel), 2);
+    assert_eq!(
+        requested.num_violations(&downlevel),
+        requested.check_limits_report(&downlevel).len()
+    );
+    assert_eq!(downlevel.num_violations(&downlevel), 0);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn limits_deserialize_defaults() {
+    // Every field must fall back to `Limits::default()` when missing.
+    assert_eq!(
+        serde_json::from_str::<Limits>("{}").unwrap(),
+        Limits::default()
+    );
+    assert_eq!(
+        serde_json::from_str::<Limits>(r#"{"maxBindGroups": 8}"#).unwrap(),
+        Limits {
+            max_bind_groups: 8,
+            ..Limits::default()
+        }
+    );
+}
+
+#[test]
+fn limits_max_min() {
+    let a = Limits {
+        max_bind_groups: 8,
+        min_uniform_buffer_offset_alignment: 64,
+        ..Limits::downlevel_webgl2_defaults()
+    };
+    let b = Limits::downlevel_defaults();
+
+    let max = a.clone().max(b.clone());
+    assert_eq!(max.max_bind_groups, 8);
+    assert_eq!(max.min_uniform_buffer_offset_alignment, 64);
+    assert_eq!(
+        max.max_storage_buffers_per_shader_stage,
+        b.max_storage_buffers_per_shader_stage
+    );
+    assert!(a.check_limits(&max));
+    assert!(b.check_limits(&max));
+
+    let min = a.clone().min(b.clone());
+    assert_eq!(min.max_bind_groups, 4);
+    assert_eq!(min.min_uniform_buffer_offset_alignment, 256);
+    assert!(min.check_limits(&a));
+    assert!(min.check_limits(&b));
+
+    assert_eq!(a.clone().max(a.clone()), a);
+
+    let subgroups = Limits {
+        min_subgroup_size: 8,
+        max_subgroup_size: 64,
+        ..Limits::default()
+    };
+    for merged in [
+        Limits::default().max(subgroups.clone()),
+        subgroups.clone().max(Limits::default()),
+        Limits::default().min(subgroups.clone()),
+        subgroups.clone().min(Limits::default()),
+    ] {
+        assert_eq!(merged.subgroup_size_range(), Some(8..=64));
+    }
+    let other = Limits {
+        min_subgroup_size: 4,
+        max_subgroup_size: 32,
+        ..Limits::default()
+    };
+    assert_eq!(
+        subgroups.clone().max(other.clone()).subgroup_size_range(),
+        Some(4..=64)
+    );
+    assert_eq!(subgroups.min(other).subgroup_size_range(), Some(8..=32));
+}
+
+#[test]
+fn limits_check_report() {
+    let requested = Limits {
+        max_bind_groups: 16,
+        min_storage_buffer_offset_alignment: 32,
+        ..Limits::default()
+    };
+    assert_eq!(
+        requested.check_limits_report(&Limits::default()),
+        [
+            LimitViolation {
+                name: "max_bind_groups",
+                requested: 16,
+                allowed: 4,
+            },
+            LimitViolation {
+                name: "min_storage_buffer_offset_alignment",
+                requested: 32,
+                allowed: 256,
+            },
+        ]
+    );
+    assert!(Limits::downlevel_defaults()
+        .check_limits_report(&Limits::default())
+        .is_empty());
+}
+
+#[test]
+fn limits_fits_bindings() {
+    let limits = Limits::downlevel_webgl2_defaults();
+    let vertex = BindingCounts {
+        sampled_textures: 4,
+        uniform_buffers: 2,
+        ..Default::default()
+    };
+    assert_eq!(limits.fits_bindings(&vertex), Ok(()));
+
+    let fragment = BindingCounts {
+        storage_buffers: 2,
+        uniform_buffers: 12,
+        ..vertex
+    };
+    assert_eq!(
+        limits.fits_bindings(&fragment),
+        Err(vec![
+            LimitViolation {
+                name: "max_storage_buffers_per_shader_stage",
+                requested: 2,
+                allowed: 0,
+            },
+            LimitViolation {
+                name: "max_uniform_buffers_per_shader_stage",
+                requested: 12,
+                allowed: 11,
+            },
+        ])
+    );
+}
+
+/// Represents the sets of additional limits on an adapter,
 /// which take place when running on downlevel backends.
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -1748,6 +3088,39 @@ impl DownlevelFlags {
         // WebGPU doesn't actually require aniso
         Self::from_bits_truncate(Self::all().bits() & !Self::ANISOTROPIC_FILTERING.bits())
     }
+
+    /// Returns the flags in [`Self::compliant()`] that are not set in self.
+    ///
+    /// These are the reasons why an adapter with these flags is not WebGPU compliant, see
+    /// [`DownlevelCapabilities::is_webgpu_compliant`].
+    #[must_use]
+    pub const fn missing_for_compliance(&self) -> Self {
+        Self::from_bits_truncate(Self::compliant().bits() & !self.bits())
+    }
+
+    /// Returns an iterator over the names of the flags in [`Self::missing_for_compliance`].
+    pub fn missing_compliance_names(&self) -> impl Iterator<Item = &'static str> {
+        self.missing_for_compliance().iter_set_names()
+    }
+}
+
+#[test]
+fn downlevel_flags_missing_for_compliance() {
+    // Roughly what a WebGL2 adapter reports.
+    let missing = DownlevelFlags::COMPUTE_SHADERS
+        | DownlevelFlags::VERTEX_STORAGE
+        | DownlevelFlags::INDIRECT_EXECUTION;
+    let webgl2 = (DownlevelFlags::compliant() - missing) | DownlevelFlags::ANISOTROPIC_FILTERING;
+
+    assert_eq!(webgl2.missing_for_compliance(), missing);
+    assert!(webgl2.missing_compliance_names().eq([
+        "COMPUTE_SHADERS",
+        "INDIRECT_EXECUTION",
+        "VERTEX_STORAGE"
+    ]));
+    assert!(DownlevelFlags::compliant()
+        .missing_for_compliance()
+        .is_empty());
 }
 
 /// Collections of shader features a device supports if they support less than WebGPU normally allows.
@@ -1763,6 +3136,17 @@ pub enum ShaderModel {
     Sm5,
 }
 
+impl ShaderModel {
+    /// Returns true if storage images are supported, which requires [`Self::Sm5`].
+    ///
+    /// The shader model does not say whether compute shaders are available; check
+    /// [`DownlevelFlags::COMPUTE_SHADERS`] for that instead.
+    #[must_use]
+    pub fn supports_storage_images(&self) -> bool {
+        *self >= Self::Sm5
+    }
+}
+
 /// Supported physical device types.
 #[repr(u8)]
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
@@ -1780,6 +3164,26 @@ pub enum DeviceType {
     Cpu,
 }
 
+impl DeviceType {
+    /// Returns the rank of the device type when selecting an adapter for high performance.
+    ///
+    /// A higher rank is more preferred, with the order being
+    /// [`DiscreteGpu`](Self::DiscreteGpu) > [`IntegratedGpu`](Self::IntegratedGpu) >
+    /// [`VirtualGpu`](Self::VirtualGpu) > [`Cpu`](Self::Cpu) > [`Other`](Self::Other).
+    ///
+    /// This is independent of the discriminants of the enum.
+    #[must_use]
+    pub const fn rank(&self) -> u8 {
+        match *self {
+            Self::DiscreteGpu => 4,
+            Self::IntegratedGpu => 3,
+            Self::VirtualGpu => 2,
+            Self::Cpu => 1,
+            Self::Other => 0,
+        }
+    }
+}
+
 //TODO: convert `vendor` and `device` to `u32`
 
 /// Information about an adapter.
@@ -1821,6 +3225,135 @@ pub struct AdapterInfo {
     pub backend: Backend,
 }
 
+impl AdapterInfo {
+    /// Returns true if the adapter is a software renderer, rather than a hardware GPU.
+    ///
+    /// This is the case if [`Self::device_type`] is [`DeviceType::Cpu`], or if the name is
+    /// that of a well known software renderer such as llvmpipe, lavapipe, WARP or SwiftShader,
+    /// as these do not always report themselves as CPU devices.
+    #[must_use]
+    pub fn is_software(&self) -> bool {
+        const SOFTWARE_RENDERERS: [&str; 5] =
+            ["llvmpipe", "lavapipe", "softpipe", "warp", "swiftshader"];
+
+        if self.device_type == DeviceType::Cpu {
+            return true;
+        }
+        let name = self.name.to_lowercase();
+        SOFTWARE_RENDERERS
+            .iter()
+            .any(|renderer| name.contains(renderer))
+    }
+
+    /// Returns the name of the vendor of the adapter, if [`Self::vendor`] is a known vendor ID.
+    #[must_use]
+    pub fn vendor_name(&self) -> Option<&'static str> {
+        Some(match self.vendor {
+            0x1002 => "AMD",
+            0x1010 => "Imagination Technologies",
+            0x106B => "Apple",
+            0x10DE => "NVIDIA",
+            0x13B5 => "ARM",
+            0x1414 => "Microsoft",
+            0x14E4 => "Broadcom",
+            0x1AE0 => "Google",
+            0x5143 => "Qualcomm",
+            0x8086 => "Intel",
+            0x10005 => "Mesa",
+            _ => return None,
+        })
+    }
+
+    /// Returns a key identifying the adapter, for caches that should survive driver updates.
+    ///
+    /// Only the stable identity of the adapter is hashed: [`Self::vendor`], [`Self::device`],
+    /// [`Self::backend`] and [`Self::device_type`]. The name and driver information are
+    /// excluded, as they may change with driver updates. Unlike the [`Hash`] implementation,
+    /// the key uses a fixed hash function (64-bit FNV-1a), so it is the same across runs and
+    /// wgpu versions.
+    #[must_use]
+    pub fn device_key(&self) -> u64 {
+        let mut hasher = Fnv1aHasher::default();
+        hasher.write(&self.vendor.to_le_bytes());
+        hasher.write(&self.device.to_le_bytes());
+        hasher.write(&[self.backend as u8, self.device_type as u8]);
+        hasher.finish()
+    }
+}
+
+/// 64-bit FNV-1a hasher, used where a hash must not depend on a random seed.
+struct Fnv1aHasher(u64);
+
+impl Default for Fnv1aHasher {
+    fn default() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Hasher for Fnv1aHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+        for &byte in bytes {
+            self.0 = (self.0 ^ u64::from(byte)).wrapping_mul(PRIME);
+        }
+    }
+}
+
+#[test]
+fn adapter_info_software() {
+    let mut info = AdapterInfo {
+        name: String::from("llvmpipe (LLVM 15.0.7, 256 bits)"),
+        vendor: 0x10005,
+        device: 0,
+        device_type: DeviceType::Other,
+        driver: String::new(),
+        driver_info: String::new(),
+        backend: Backend::Gl,
+    };
+    assert!(info.is_software());
+    assert_eq!(info.vendor_name(), Some("Mesa"));
+
+    info.name = String::from("NVIDIA GeForce RTX 3080");
+    info.vendor = 0x10DE;
+    info.device_type = DeviceType::DiscreteGpu;
+    assert!(!info.is_software());
+    assert_eq!(info.vendor_name(), Some("NVIDIA"));
+
+    info.name = String::from("Microsoft Basic Render Driver");
+    info.vendor = 0x1234;
+    info.device_type = DeviceType::Cpu;
+    assert!(info.is_software());
+    assert_eq!(info.vendor_name(), None);
+}
+
+#[test]
+fn adapter_info_device_key() {
+    let mut info = AdapterInfo {
+        name: String::from("NVIDIA GeForce RTX 3080"),
+        vendor: 0x10DE,
+        device: 0x2206,
+        device_type: DeviceType::DiscreteGpu,
+        driver: String::from("NVIDIA"),
+        driver_info: String::from("550.54.14"),
+        backend: Backend::Vulkan,
+    };
+    let key = info.device_key();
+    // The key must not change between wgpu versions.
+    assert_eq!(key, 0x7029_f5f5_bad2_1740);
+
+    info.driver_info = String::from("555.42.02");
+    info.name = String::from("NVIDIA GeForce RTX 3080 (renamed)");
+    assert_eq!(info.device_key(), key);
+
+    info.backend = Backend::Gl;
+    assert_ne!(info.device_key(), key);
+}
+
 /// Hints to the device about the memory allocation strategy.
 ///
 /// Some backends may ignore these hints.
@@ -1854,6 +3387,103 @@ pub enum MemoryHints {
     },
 }
 
+impl MemoryHints {
+    /// Creates [`MemoryHints::Manual`] with the given range of memory block sizes.
+    ///
+    /// Both `start` and `end` must be powers of two, and `start` must not be greater
+    /// than `end`. As zero is not a power of two, this also ensures the range is non-zero.
+    pub fn manual(start: u64, end: u64) -> Result<Self, MemoryHintsError> {
+        Self::validate_block_sizes(start, end)?;
+        Ok(Self::Manual {
+            suballocated_device_memory_block_size: start..end,
+        })
+    }
+
+    /// Returns true if the hints are valid.
+    ///
+    /// [`MemoryHints::Performance`] and [`MemoryHints::MemoryUsage`] are always valid,
+    /// while [`MemoryHints::Manual`] must follow the rules of [`MemoryHints::manual`].
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        match *self {
+            Self::Performance | Self::MemoryUsage => true,
+            Self::Manual {
+                suballocated_device_memory_block_size: Range { start, end },
+            } => Self::validate_block_sizes(start, end).is_ok(),
+        }
+    }
+
+    fn validate_block_sizes(start: u64, end: u64) -> Result<(), MemoryHintsError> {
+        if start > end {
+            return Err(MemoryHintsError::InvalidRange { start, end });
+        }
+        for size in [start, end] {
+            if !size.is_power_of_two() {
+                return Err(MemoryHintsError::NotPowerOfTwo(size));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Error returned by [`MemoryHints::manual`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MemoryHintsError {
+    /// The start of the block size range is greater than its end.
+    InvalidRange {
+        /// The start of the range.
+        start: u64,
+        /// The end of the range.
+        end: u64,
+    },
+    /// A block size is not a power of two.
+    NotPowerOfTwo(u64),
+}
+
+impl core::fmt::Display for MemoryHintsError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match *self {
+            Self::InvalidRange { start, end } => write!(
+                f,
+                "Memory block size range start {start} is greater than its end {end}"
+            ),
+            Self::NotPowerOfTwo(size) => {
+                write!(f, "Memory block size {size} is not a power of two")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for MemoryHintsError {}
+
+#[test]
+fn memory_hints_manual() {
+    let hints = MemoryHints::manual(1 << 20, 1 << 26).unwrap();
+    assert!(hints.is_valid());
+    assert!(MemoryHints::manual(1 << 20, 1 << 20).is_ok());
+    assert_eq!(
+        MemoryHints::manual(1 << 26, 1 << 20).unwrap_err(),
+        MemoryHintsError::InvalidRange {
+            start: 1 << 26,
+            end: 1 << 20
+        }
+    );
+    assert_eq!(
+        MemoryHints::manual(0, 1 << 20).unwrap_err(),
+        MemoryHintsError::NotPowerOfTwo(0)
+    );
+    assert_eq!(
+        MemoryHints::manual(1 << 20, 3 << 20).unwrap_err(),
+        MemoryHintsError::NotPowerOfTwo(3 << 20)
+    );
+    assert!(!MemoryHints::Manual {
+        suballocated_device_memory_block_size: 0..1024,
+    }
+    .is_valid());
+    assert!(MemoryHints::MemoryUsage.is_valid());
+}
+
 /// Describes a [`Device`](../wgpu/struct.Device.html).
 ///
 /// Corresponds to [WebGPU `GPUDeviceDescriptor`](
@@ -1880,6 +3510,29 @@ pub struct DeviceDescriptor<L> {
 }
 
 impl<L> DeviceDescriptor<L> {
+    /// Creates a device descriptor with the given label, which requires no features and
+    /// the [`Limits::downlevel_webgl2_defaults`].
+    ///
+    /// Unlike [`Default::default`], this is a `const fn`, so it can be used in statics
+    /// and other const contexts.
+    ///
+    /// ```rust
+    /// # use wgpu_types::{DeviceDescriptor, Features};
+    /// static DESCRIPTOR: DeviceDescriptor<Option<&str>> = DeviceDescriptor {
+    ///     required_features: Features::DEPTH_CLIP_CONTROL,
+    ///     ..DeviceDescriptor::minimal(Some("device"))
+    /// };
+    /// ```
+    #[must_use]
+    pub const fn minimal(label: L) -> Self {
+        Self {
+            label,
+            required_features: Features::empty(),
+            required_limits: Limits::downlevel_webgl2_defaults(),
+            memory_hints: MemoryHints::Performance,
+        }
+    }
+
     /// Takes a closure and maps the label of the device descriptor into another.
     #[must_use]
     pub fn map_label<K>(&self, fun: impl FnOnce(&L) -> K) -> DeviceDescriptor<K> {
@@ -1890,6 +3543,96 @@ impl<L> DeviceDescriptor<L> {
             memory_hints: self.memory_hints.clone(),
         }
     }
+
+    /// Checks that the required features and limits can be provided by an adapter with the
+    /// given features and limits.
+    ///
+    /// Unlike requesting a device, this reports every missing feature and every limit violation
+    /// at once.
+    pub fn validate_against(
+        &self,
+        adapter_features: Features,
+        adapter_limits: &Limits,
+    ) -> Result<(), DeviceRequestValidation> {
+        let missing_features = self.required_features.missing_from(adapter_features);
+        let limit_violations = self.required_limits.check_limits_report(adapter_limits);
+        if missing_features.is_empty() && limit_violations.is_empty() {
+            Ok(())
+        } else {
+            Err(DeviceRequestValidation {
+                missing_features,
+                limit_violations,
+            })
+        }
+    }
+}
+
+/// Error returned by [`DeviceDescriptor::validate_against`] when an adapter cannot provide the
+/// requested features or limits.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DeviceRequestValidation {
+    /// Names of the required features that the adapter does not support, as returned by
+    /// [`Features::missing_from`].
+    pub missing_features: Vec<&'static str>,
+    /// Required limits that are better than the adapter limits, as returned by
+    /// [`Limits::check_limits_report`].
+    pub limit_violations: Vec<LimitViolation>,
+}
+
+impl core::fmt::Display for DeviceRequestValidation {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("Device request is not supported by the adapter")?;
+        if !self.missing_features.is_empty() {
+            write!(
+                f,
+                "; missing features: {}",
+                self.missing_features.join(", ")
+            )?;
+        }
+        for violation in &self.limit_violations {
+            write!(f, "; {violation}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DeviceRequestValidation {}
+
+#[test]
+fn device_descriptor_validate_against() {
+    use alloc::string::ToString;
+
+    let desc = DeviceDescriptor {
+        required_features: Features::DEPTH_CLIP_CONTROL | Features::TIMESTAMP_QUERY,
+        required_limits: Limits {
+            max_bind_groups: 8,
+            ..Limits::downlevel_defaults()
+        },
+        ..DeviceDescriptor::minimal(None::<&str>)
+    };
+    assert_eq!(
+        desc.validate_against(desc.required_features, &desc.required_limits),
+        Ok(())
+    );
+
+    let err = desc
+        .validate_against(Features::DEPTH_CLIP_CONTROL, &Limits::downlevel_defaults())
+        .unwrap_err();
+    assert_eq!(err.missing_features, ["TIMESTAMP_QUERY"]);
+    assert_eq!(
+        err.limit_violations,
+        [LimitViolation {
+            name: "max_bind_groups",
+            requested: 8,
+            allowed: 4,
+        }]
+    );
+    assert_eq!(
+        err.to_string(),
+        "Device request is not supported by the adapter; missing features: TIMESTAMP_QUERY; \
+         Limit 'max_bind_groups' value 8 is better than allowed 4"
+    );
 }
 
 bitflags::bitflags! {
@@ -1919,6 +3662,41 @@ bitflags::bitflags! {
     }
 }
 
+impl ShaderStages {
+    /// Returns all of the stages of a render pipeline.
+    ///
+    /// This is currently the same as [`ShaderStages::VERTEX_FRAGMENT`], but will also include any
+    /// graphics stages added in the future, so prefer it when validating render pipelines.
+    #[must_use]
+    pub const fn all_graphics() -> Self {
+        Self::VERTEX.union(Self::FRAGMENT)
+    }
+
+    /// Returns true if the stages are not empty and are only stages of a render pipeline.
+    #[must_use]
+    pub const fn is_graphics(&self) -> bool {
+        !self.is_empty() && Self::all_graphics().contains(*self)
+    }
+
+    /// Returns true if the stages are exactly the stage of a compute pipeline.
+    #[must_use]
+    pub const fn is_compute(&self) -> bool {
+        self.bits() == Self::COMPUTE.bits()
+    }
+}
+
+#[test]
+fn shader_stages_pipeline_masks() {
+    assert_eq!(ShaderStages::all_graphics(), ShaderStages::VERTEX_FRAGMENT);
+    assert!(ShaderStages::VERTEX.is_graphics());
+    assert!(ShaderStages::VERTEX_FRAGMENT.is_graphics());
+    assert!(!ShaderStages::NONE.is_graphics());
+    assert!(!(ShaderStages::FRAGMENT | ShaderStages::COMPUTE).is_graphics());
+    assert!(ShaderStages::COMPUTE.is_compute());
+    assert!(!ShaderStages::all().is_compute());
+    assert!(!ShaderStages::VERTEX.is_compute());
+}
+
 /// Order in which TextureData is laid out in memory.
 #[derive(Clone, Copy, Default, Debug, PartialEq, Eq, Hash)]
 pub enum TextureDataOrder {
@@ -1984,6 +3762,64 @@ impl TextureViewDimension {
             Self::D3 => TextureDimension::D3,
         }
     }
+
+    /// Returns true if the view dimension is an array of layers, that is
+    /// [`Self::D2Array`] or [`Self::CubeArray`].
+    #[must_use]
+    pub fn is_array(self) -> bool {
+        matches!(self, Self::D2Array | Self::CubeArray)
+    }
+
+    /// Returns true if the view dimension is a cube map, that is
+    /// [`Self::Cube`] or [`Self::CubeArray`].
+    #[must_use]
+    pub fn is_cube(self) -> bool {
+        matches!(self, Self::Cube | Self::CubeArray)
+    }
+
+    /// Returns the number the array layer count of a view of this dimension must be a multiple of.
+    ///
+    /// This is 6 for cube maps, which have one layer per face, and 1 otherwise.
+    #[must_use]
+    pub fn required_layer_count_multiple(self) -> u32 {
+        if self.is_cube() {
+            6
+        } else {
+            1
+        }
+    }
+}
+
+#[test]
+fn texture_view_dimension_layers() {
+    use TextureViewDimension as Tvd;
+
+    let properties = [
+        Tvd::D1,
+        Tvd::D2,
+        Tvd::D2Array,
+        Tvd::Cube,
+        Tvd::CubeArray,
+        Tvd::D3,
+    ]
+    .map(|dim| {
+        (
+            dim.is_array(),
+            dim.is_cube(),
+            dim.required_layer_count_multiple(),
+        )
+    });
+    assert_eq!(
+        properties,
+        [
+            (false, false, 1),
+            (false, false, 1),
+            (true, false, 1),
+            (false, true, 6),
+            (true, true, 6),
+            (false, false, 1),
+        ]
+    );
 }
 
 /// Alpha blend factor.
@@ -2050,6 +3886,18 @@ impl BlendFactor {
             _ => false,
         }
     }
+
+    /// Returns `true` if the blend factor references the constant color, which is
+    /// set with [`RenderPass::set_blend_constant`][RPsbc].
+    ///
+    /// [RPsbc]: ../wgpu/struct.RenderPass.html#method.set_blend_constant
+    #[must_use]
+    pub fn uses_constant(&self) -> bool {
+        match self {
+            BlendFactor::Constant | BlendFactor::OneMinusConstant => true,
+            _ => false,
+        }
+    }
 }
 
 /// Alpha blend operation.
@@ -2114,13 +3962,21 @@ impl BlendComponent {
     /// set independently on a render command encoder.
     #[must_use]
     pub fn uses_constant(&self) -> bool {
-        match (self.src_factor, self.dst_factor) {
-            (BlendFactor::Constant, _)
-            | (BlendFactor::OneMinusConstant, _)
-            | (_, BlendFactor::Constant)
-            | (_, BlendFactor::OneMinusConstant) => true,
-            (_, _) => false,
-        }
+        self.src_factor.uses_constant() || self.dst_factor.uses_constant()
+    }
+
+    /// Returns true if the component replaces the destination with the source, like
+    /// [`Self::REPLACE`].
+    ///
+    /// This also detects `src * 1 - dst * 0`, which is equivalent to [`Self::REPLACE`].
+    #[must_use]
+    pub fn is_replace(&self) -> bool {
+        self.src_factor == BlendFactor::One
+            && self.dst_factor == BlendFactor::Zero
+            && matches!(
+                self.operation,
+                BlendOperation::Add | BlendOperation::Subtract
+            )
     }
 }
 
@@ -2168,6 +4024,91 @@ impl BlendState {
         color: BlendComponent::OVER,
         alpha: BlendComponent::OVER,
     };
+
+    /// Blend mode that adds the output of the shader to the target: `src * 1 + dst * 1`.
+    ///
+    /// This does not take alpha into account, so the shader output is expected to have
+    /// premultiplied alpha if it should be faded out by its alpha. With non-premultiplied
+    /// alpha, use [`BlendFactor::SrcAlpha`] as the color source factor instead.
+    pub const ADDITIVE: Self = Self {
+        color: BlendComponent {
+            src_factor: BlendFactor::One,
+            dst_factor: BlendFactor::One,
+            operation: BlendOperation::Add,
+        },
+        alpha: BlendComponent {
+            src_factor: BlendFactor::One,
+            dst_factor: BlendFactor::One,
+            operation: BlendOperation::Add,
+        },
+    };
+
+    /// Blend mode that multiplies the target by the output of the shader: `src * dst + dst * 0`.
+    ///
+    /// A fully transparent output should leave the target unchanged, so with premultiplied
+    /// alpha the shader needs to output `1 - alpha + color` rather than just the premultiplied
+    /// color for partially transparent outputs.
+    pub const MULTIPLICATIVE: Self = Self {
+        color: BlendComponent {
+            src_factor: BlendFactor::Dst,
+            dst_factor: BlendFactor::Zero,
+            operation: BlendOperation::Add,
+        },
+        alpha: BlendComponent {
+            src_factor: BlendFactor::Dst,
+            dst_factor: BlendFactor::Zero,
+            operation: BlendOperation::Add,
+        },
+    };
+
+    /// Returns true if both the color and alpha components replace the destination with the
+    /// source, see [`BlendComponent::is_replace`].
+    ///
+    /// Such a blend state is equivalent to not blending at all, that is
+    /// [`ColorTargetState::blend`] being `None`.
+    #[must_use]
+    pub fn is_opaque(&self) -> bool {
+        self.color.is_replace() && self.alpha.is_replace()
+    }
+}
+
+#[test]
+fn blend_factor_uses_constant() {
+    assert!(BlendFactor::Constant.uses_constant());
+    assert!(BlendFactor::OneMinusConstant.uses_constant());
+    assert!(!BlendFactor::SrcAlpha.uses_constant());
+    assert!(!BlendFactor::Src1.uses_constant());
+
+    let component = BlendComponent {
+        src_factor: BlendFactor::One,
+        dst_factor: BlendFactor::OneMinusConstant,
+        operation: BlendOperation::Add,
+    };
+    assert!(component.uses_constant());
+    assert!(!BlendComponent::OVER.uses_constant());
+}
+
+#[test]
+fn blend_state_is_opaque() {
+    assert!(BlendState::REPLACE.is_opaque());
+    assert!(BlendState {
+        color: BlendComponent {
+            operation: BlendOperation::Subtract,
+            ..BlendComponent::REPLACE
+        },
+        alpha: BlendComponent::default(),
+    }
+    .is_opaque());
+    assert!(!BlendState {
+        color: BlendComponent {
+            operation: BlendOperation::Min,
+            ..BlendComponent::REPLACE
+        },
+        alpha: BlendComponent::REPLACE,
+    }
+    .is_opaque());
+    assert!(!BlendState::ALPHA_BLENDING.is_opaque());
+    assert!(!BlendState::PREMULTIPLIED_ALPHA_BLENDING.is_opaque());
 }
 
 /// Describes the color state of a render pipeline.
@@ -2192,14 +4133,61 @@ pub struct ColorTargetState {
     pub write_mask: ColorWrites,
 }
 
-impl From<TextureFormat> for ColorTargetState {
-    fn from(format: TextureFormat) -> Self {
+impl ColorTargetState {
+    /// Target of the given format without blending, writing all channels.
+    ///
+    /// This is the same as `ColorTargetState::from(format)`.
+    #[must_use]
+    pub const fn opaque(format: TextureFormat) -> Self {
         Self {
             format,
             blend: None,
             write_mask: ColorWrites::ALL,
         }
     }
+
+    /// Target of the given format with [`BlendState::ALPHA_BLENDING`], writing all channels.
+    #[must_use]
+    pub const fn alpha_blended(format: TextureFormat) -> Self {
+        Self {
+            format,
+            blend: Some(BlendState::ALPHA_BLENDING),
+            write_mask: ColorWrites::ALL,
+        }
+    }
+
+    /// Target of the given format with [`BlendState::PREMULTIPLIED_ALPHA_BLENDING`], writing
+    /// all channels.
+    #[must_use]
+    pub const fn premultiplied(format: TextureFormat) -> Self {
+        Self {
+            format,
+            blend: Some(BlendState::PREMULTIPLIED_ALPHA_BLENDING),
+            write_mask: ColorWrites::ALL,
+        }
+    }
+}
+
+impl From<TextureFormat> for ColorTargetState {
+    fn from(format: TextureFormat) -> Self {
+        Self::opaque(format)
+    }
+}
+
+#[test]
+fn color_target_state_constructors() {
+    let format = TextureFormat::Bgra8UnormSrgb;
+    assert_eq!(ColorTargetState::opaque(format), format.into());
+    assert_eq!(
+        ColorTargetState::alpha_blended(format).blend,
+        Some(BlendState::ALPHA_BLENDING)
+    );
+    let premultiplied = ColorTargetState::premultiplied(format);
+    assert_eq!(
+        premultiplied.blend,
+        Some(BlendState::PREMULTIPLIED_ALPHA_BLENDING)
+    );
+    assert_eq!(premultiplied.write_mask, ColorWrites::ALL);
 }
 
 /// Primitive type the input mesh is composed of.
@@ -2241,6 +4229,47 @@ impl PrimitiveTopology {
             Self::LineStrip | Self::TriangleStrip => true,
         }
     }
+
+    /// Returns the number of vertices that make up a single primitive of this topology.
+    ///
+    /// This is 1 for points, 2 for lines and 3 for triangles, regardless of whether
+    /// the topology is a list or a strip.
+    #[must_use]
+    pub fn vertices_per_primitive(&self) -> u32 {
+        match *self {
+            Self::PointList => 1,
+            Self::LineList | Self::LineStrip => 2,
+            Self::TriangleList | Self::TriangleStrip => 3,
+        }
+    }
+
+    /// Returns the number of primitives drawn from `vertex_count` vertices (or indices).
+    ///
+    /// For list topologies, any trailing vertices which do not form a complete primitive
+    /// are ignored. For strip topologies, every vertex after the first primitive adds
+    /// another primitive. If there are not enough vertices for a single primitive, this
+    /// returns 0.
+    #[must_use]
+    pub fn primitive_count(&self, vertex_count: u32) -> u32 {
+        let per_primitive = self.vertices_per_primitive();
+        if self.is_strip() {
+            vertex_count.saturating_sub(per_primitive - 1)
+        } else {
+            vertex_count / per_primitive
+        }
+    }
+}
+
+#[test]
+fn primitive_topology_primitive_count() {
+    assert_eq!(PrimitiveTopology::PointList.primitive_count(5), 5);
+    assert_eq!(PrimitiveTopology::LineList.primitive_count(5), 2);
+    assert_eq!(PrimitiveTopology::LineStrip.primitive_count(5), 4);
+    assert_eq!(PrimitiveTopology::TriangleList.primitive_count(7), 2);
+    assert_eq!(PrimitiveTopology::TriangleStrip.primitive_count(6), 4);
+    assert_eq!(PrimitiveTopology::TriangleStrip.primitive_count(2), 0);
+    assert_eq!(PrimitiveTopology::LineStrip.primitive_count(1), 0);
+    assert_eq!(PrimitiveTopology::TriangleList.primitive_count(0), 0);
 }
 
 /// Vertex winding order which classifies the "front" face of a triangle.
@@ -2263,6 +4292,19 @@ pub enum FrontFace {
     Cw = 1,
 }
 
+impl FrontFace {
+    /// Returns the opposite winding order.
+    ///
+    /// Useful when drawing geometry mirrored by a transform with a negative determinant.
+    #[must_use]
+    pub const fn inverse(self) -> Self {
+        match self {
+            Self::Ccw => Self::Cw,
+            Self::Cw => Self::Ccw,
+        }
+    }
+}
+
 /// Face of a vertex.
 ///
 /// Corresponds to [WebGPU `GPUCullMode`](
@@ -2279,6 +4321,29 @@ pub enum Face {
     Back = 1,
 }
 
+impl Face {
+    /// Returns the other face.
+    #[must_use]
+    pub const fn opposite(self) -> Self {
+        match self {
+            Self::Front => Self::Back,
+            Self::Back => Self::Front,
+        }
+    }
+}
+
+#[test]
+fn face_inverse() {
+    for front_face in [FrontFace::Ccw, FrontFace::Cw] {
+        assert_ne!(front_face.inverse(), front_face);
+        assert_eq!(front_face.inverse().inverse(), front_face);
+    }
+    for face in [Face::Front, Face::Back] {
+        assert_ne!(face.opposite(), face);
+        assert_eq!(face.opposite().opposite(), face);
+    }
+}
+
 /// Type of drawing mode for polygons
 #[repr(C)]
 #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
@@ -2337,6 +4402,148 @@ pub struct PrimitiveState {
     pub conservative: bool,
 }
 
+impl PrimitiveState {
+    /// Returns this state with [`Self::topology`] set to `topology`.
+    #[must_use]
+    pub const fn with_topology(mut self, topology: PrimitiveTopology) -> Self {
+        self.topology = topology;
+        self
+    }
+
+    /// Returns this state with [`Self::strip_index_format`] set to `format`.
+    #[must_use]
+    pub const fn with_strip_index_format(mut self, format: Option<IndexFormat>) -> Self {
+        self.strip_index_format = format;
+        self
+    }
+
+    /// Returns this state with [`Self::front_face`] set to `front_face`.
+    #[must_use]
+    pub const fn with_front_face(mut self, front_face: FrontFace) -> Self {
+        self.front_face = front_face;
+        self
+    }
+
+    /// Returns this state with [`Self::cull_mode`] set to `cull_mode`.
+    #[must_use]
+    pub const fn with_cull_mode(mut self, cull_mode: Option<Face>) -> Self {
+        self.cull_mode = cull_mode;
+        self
+    }
+
+    /// Returns this state with [`Self::unclipped_depth`] set to `unclipped_depth`.
+    #[must_use]
+    pub const fn unclipped_depth(mut self, unclipped_depth: bool) -> Self {
+        self.unclipped_depth = unclipped_depth;
+        self
+    }
+
+    /// Returns this state with [`Self::polygon_mode`] set to `polygon_mode`.
+    #[must_use]
+    pub const fn with_polygon_mode(mut self, polygon_mode: PolygonMode) -> Self {
+        self.polygon_mode = polygon_mode;
+        self
+    }
+
+    /// Returns this state with [`Self::conservative`] set to `conservative`.
+    #[must_use]
+    pub const fn conservative(mut self, conservative: bool) -> Self {
+        self.conservative = conservative;
+        self
+    }
+
+    /// Checks that [`Self::strip_index_format`] is only set for strip topologies.
+    ///
+    /// Strip topologies may have a strip index format, which is required to draw them with
+    /// indices. Non-strip topologies must not have one.
+    pub fn validate_strip_index_format(&self) -> Result<(), PrimitiveStateError> {
+        match self.strip_index_format {
+            Some(strip_index_format) if !self.topology.is_strip() => {
+                Err(PrimitiveStateError::StripIndexFormatForNonStripTopology {
+                    strip_index_format,
+                    topology: self.topology,
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Error returned by [`PrimitiveState::validate_strip_index_format`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PrimitiveStateError {
+    /// A strip index format was set while using a non-strip topology.
+    StripIndexFormatForNonStripTopology {
+        /// The strip index format that was set.
+        strip_index_format: IndexFormat,
+        /// The non-strip topology.
+        topology: PrimitiveTopology,
+    },
+}
+
+impl core::fmt::Display for PrimitiveStateError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match *self {
+            Self::StripIndexFormatForNonStripTopology {
+                strip_index_format,
+                topology,
+            } => write!(
+                f,
+                "Strip index format was not set to None but to {strip_index_format:?} \
+                 while using the non-strip topology {topology:?}"
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PrimitiveStateError {}
+
+#[test]
+fn primitive_state_builder() {
+    let state = PrimitiveState::default()
+        .with_topology(PrimitiveTopology::TriangleStrip)
+        .with_strip_index_format(Some(IndexFormat::Uint16))
+        .with_cull_mode(Some(Face::Back))
+        .with_front_face(FrontFace::Cw)
+        .with_polygon_mode(PolygonMode::Line)
+        .unclipped_depth(true)
+        .conservative(true);
+    assert_eq!(
+        state,
+        PrimitiveState {
+            topology: PrimitiveTopology::TriangleStrip,
+            strip_index_format: Some(IndexFormat::Uint16),
+            front_face: FrontFace::Cw,
+            cull_mode: Some(Face::Back),
+            unclipped_depth: true,
+            polygon_mode: PolygonMode::Line,
+            conservative: true,
+        }
+    );
+}
+
+#[test]
+fn primitive_state_validate_strip_index_format() {
+    let strip = PrimitiveState::default().with_topology(PrimitiveTopology::TriangleStrip);
+    assert_eq!(strip.validate_strip_index_format(), Ok(()));
+    assert_eq!(
+        strip
+            .with_strip_index_format(Some(IndexFormat::Uint32))
+            .validate_strip_index_format(),
+        Ok(())
+    );
+
+    let list = PrimitiveState::default().with_strip_index_format(Some(IndexFormat::Uint16));
+    assert_eq!(
+        list.validate_strip_index_format(),
+        Err(PrimitiveStateError::StripIndexFormatForNonStripTopology {
+            strip_index_format: IndexFormat::Uint16,
+            topology: PrimitiveTopology::TriangleList,
+        })
+    );
+}
+
 /// Describes the multi-sampling state of a render pipeline.
 ///
 /// Corresponds to [WebGPU `GPUMultisampleState`](
@@ -2371,6 +4578,51 @@ impl Default for MultisampleState {
     }
 }
 
+impl MultisampleState {
+    /// Returns true if [`Self::count`] is a sample count that may be supported, that is
+    /// 1, 2, 4, 8 or 16.
+    ///
+    /// Whether a given count is actually supported depends on the format, see
+    /// [`TextureFormatFeatureFlags::sample_count_supported`].
+    #[must_use]
+    pub fn is_valid_count(&self) -> bool {
+        matches!(self.count, 1 | 2 | 4 | 8 | 16)
+    }
+
+    /// Returns the state with the bits of [`Self::mask`] beyond [`Self::count`] cleared.
+    ///
+    /// Only the lowest `count` bits of the mask have an effect, so states which only differ
+    /// in the higher bits are functionally identical, but do not compare or hash equal.
+    /// Canonicalize the state before using it as a key, for example in a pipeline cache,
+    /// so that such states share an entry.
+    #[must_use]
+    pub fn canonicalize(&self) -> Self {
+        let count_mask = 1u64
+            .checked_shl(self.count)
+            .map_or(!0, |sample_bit| sample_bit - 1);
+        Self {
+            mask: self.mask & count_mask,
+            ..*self
+        }
+    }
+}
+
+#[test]
+fn multisample_state_canonicalize() {
+    let a = MultisampleState {
+        count: 4,
+        mask: !0,
+        alpha_to_coverage_enabled: false,
+    };
+    let b = MultisampleState { mask: 0xF0F, ..a };
+    assert_ne!(a, b);
+    assert_eq!(a.canonicalize(), b.canonicalize());
+    assert_eq!(a.canonicalize().mask, 0xF);
+
+    let c = MultisampleState { mask: 0xF05, ..a };
+    assert_ne!(a.canonicalize(), c.canonicalize());
+}
+
 bitflags::bitflags! {
     /// Feature flags for a texture format.
     #[repr(transparent)]
@@ -2436,6 +4688,31 @@ impl TextureFormatFeatureFlags {
             .filter(|&sc| self.sample_count_supported(sc))
             .collect()
     }
+
+    /// Returns the largest supported sample count which is less than or equal to `requested`.
+    ///
+    /// This is useful for degrading MSAA when the requested sample count isn't supported.
+    /// A sample count of 1 is always supported, so this returns 1 if `requested` is 0 or 1.
+    #[must_use]
+    pub fn nearest_valid_sample_count(&self, requested: u32) -> u32 {
+        [16, 8, 4, 2]
+            .into_iter()
+            .find(|&sc| sc <= requested && self.sample_count_supported(sc))
+            .unwrap_or(1)
+    }
+}
+
+#[test]
+fn nearest_valid_sample_count() {
+    use TextureFormatFeatureFlags as tfsc;
+
+    let flags = tfsc::MULTISAMPLE_X2 | tfsc::MULTISAMPLE_X4;
+    assert_eq!(flags.nearest_valid_sample_count(0), 1);
+    assert_eq!(flags.nearest_valid_sample_count(1), 1);
+    assert_eq!(flags.nearest_valid_sample_count(3), 2);
+    assert_eq!(flags.nearest_valid_sample_count(4), 4);
+    assert_eq!(flags.nearest_valid_sample_count(16), 4);
+    assert_eq!(tfsc::empty().nearest_valid_sample_count(8), 1);
 }
 
 /// Features supported by a given texture format
@@ -2450,6 +4727,47 @@ pub struct TextureFormatFeatures {
     pub flags: TextureFormatFeatureFlags,
 }
 
+impl TextureFormatFeatures {
+    /// Returns true if all of the given `usage` bits are allowed for the format.
+    #[must_use]
+    pub const fn allows(&self, usage: TextureUsages) -> bool {
+        self.allowed_usages.contains(usage)
+    }
+
+    /// Returns true if the format can be sampled with a linear filter.
+    ///
+    /// Shorthand for checking [`TextureFormatFeatureFlags::FILTERABLE`].
+    #[must_use]
+    pub const fn supports_filtering(&self) -> bool {
+        self.flags.contains(TextureFormatFeatureFlags::FILTERABLE)
+    }
+
+    /// Returns true if the format can be used as a color target with blending enabled.
+    ///
+    /// Shorthand for checking [`TextureFormatFeatureFlags::BLENDABLE`].
+    #[must_use]
+    pub const fn supports_blending(&self) -> bool {
+        self.flags.contains(TextureFormatFeatureFlags::BLENDABLE)
+    }
+}
+
+#[test]
+fn texture_format_features_shorthands() {
+    let features = TextureFormat::Rgba8Unorm.guaranteed_format_features(Features::empty());
+    assert!(features.allows(TextureUsages::TEXTURE_BINDING | TextureUsages::RENDER_ATTACHMENT));
+    assert!(features.allows(TextureUsages::empty()));
+    assert!(features.supports_filtering());
+    assert!(features.supports_blending());
+
+    let features = TextureFormat::R32Uint.guaranteed_format_features(Features::empty());
+    assert!(features.allows(TextureUsages::STORAGE_BINDING));
+    assert!(!features.supports_filtering());
+    assert!(!features.supports_blending());
+
+    let features = TextureFormat::Bc1RgbaUnorm.guaranteed_format_features(Features::empty());
+    assert!(!features.allows(TextureUsages::RENDER_ATTACHMENT));
+}
+
 /// ASTC block dimensions
 #[repr(C)]
 #[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
@@ -2485,6 +4803,26 @@ pub enum AstcBlock {
     B12x12,
 }
 
+impl AstcBlock {
+    /// All ASTC block dimensions.
+    pub const ALL: [Self; 14] = [
+        Self::B4x4,
+        Self::B5x4,
+        Self::B5x5,
+        Self::B6x5,
+        Self::B6x6,
+        Self::B8x5,
+        Self::B8x6,
+        Self::B8x8,
+        Self::B10x5,
+        Self::B10x6,
+        Self::B10x8,
+        Self::B10x10,
+        Self::B12x10,
+        Self::B12x12,
+    ];
+}
+
 /// ASTC RGBA channel
 #[repr(C)]
 #[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
@@ -2504,6 +4842,11 @@ pub enum AstcChannel {
     Hdr,
 }
 
+impl AstcChannel {
+    /// All ASTC channel types.
+    pub const ALL: [Self; 3] = [Self::Unorm, Self::UnormSrgb, Self::Hdr];
+}
+
 /// Underlying texture data format.
 ///
 /// If there is a conversion in the format (such as srgb -> linear), the conversion listed here is for
@@ -2835,6 +5178,141 @@ pub enum TextureFormat {
     },
 }
 
+/// Error returned when a string does not name a valid [`TextureFormat`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ParseTextureFormatError;
+
+impl core::fmt::Display for ParseTextureFormatError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("invalid texture format")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseTextureFormatError {}
+
+impl core::str::FromStr for TextureFormat {
+    type Err = ParseTextureFormatError;
+
+    /// Parses a texture format from the names used by its serialized form,
+    /// such as `"rgba8unorm-srgb"` or `"astc-4x4-unorm"`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let format = match s {
+            "r8unorm" => TextureFormat::R8Unorm,
+            "r8snorm" => TextureFormat::R8Snorm,
+            "r8uint" => TextureFormat::R8Uint,
+            "r8sint" => TextureFormat::R8Sint,
+            "r16uint" => TextureFormat::R16Uint,
+            "r16sint" => TextureFormat::R16Sint,
+            "r16unorm" => TextureFormat::R16Unorm,
+            "r16snorm" => TextureFormat::R16Snorm,
+            "r16float" => TextureFormat::R16Float,
+            "rg8unorm" => TextureFormat::Rg8Unorm,
+            "rg8snorm" => TextureFormat::Rg8Snorm,
+            "rg8uint" => TextureFormat::Rg8Uint,
+            "rg8sint" => TextureFormat::Rg8Sint,
+            "r32uint" => TextureFormat::R32Uint,
+            "r32sint" => TextureFormat::R32Sint,
+            "r32float" => TextureFormat::R32Float,
+            "rg16uint" => TextureFormat::Rg16Uint,
+            "rg16sint" => TextureFormat::Rg16Sint,
+            "rg16unorm" => TextureFormat::Rg16Unorm,
+            "rg16snorm" => TextureFormat::Rg16Snorm,
+            "rg16float" => TextureFormat::Rg16Float,
+            "rgba8unorm" => TextureFormat::Rgba8Unorm,
+            "rgba8unorm-srgb" => TextureFormat::Rgba8UnormSrgb,
+            "rgba8snorm" => TextureFormat::Rgba8Snorm,
+            "rgba8uint" => TextureFormat::Rgba8Uint,
+            "rgba8sint" => TextureFormat::Rgba8Sint,
+            "bgra8unorm" => TextureFormat::Bgra8Unorm,
+            "bgra8unorm-srgb" => TextureFormat::Bgra8UnormSrgb,
+            "rgb10a2uint" => TextureFormat::Rgb10a2Uint,
+            "rgb10a2unorm" => TextureFormat::Rgb10a2Unorm,
+            "rg11b10ufloat" => TextureFormat::Rg11b10Ufloat,
+            "r64uint" => TextureFormat::R64Uint,
+            "rg32uint" => TextureFormat::Rg32Uint,
+            "rg32sint" => TextureFormat::Rg32Sint,
+            "rg32float" => TextureFormat::Rg32Float,
+            "rgba16uint" => TextureFormat::Rgba16Uint,
+            "rgba16sint" => TextureFormat::Rgba16Sint,
+            "rgba16unorm" => TextureFormat::Rgba16Unorm,
+            "rgba16snorm" => TextureFormat::Rgba16Snorm,
+            "rgba16float" => TextureFormat::Rgba16Float,
+            "rgba32uint" => TextureFormat::Rgba32Uint,
+            "rgba32sint" => TextureFormat::Rgba32Sint,
+            "rgba32float" => TextureFormat::Rgba32Float,
+            "stencil8" => TextureFormat::Stencil8,
+            "depth32float" => TextureFormat::Depth32Float,
+            "depth32float-stencil8" => TextureFormat::Depth32FloatStencil8,
+            "depth16unorm" => TextureFormat::Depth16Unorm,
+            "depth24plus" => TextureFormat::Depth24Plus,
+            "depth24plus-stencil8" => TextureFormat::Depth24PlusStencil8,
+            "nv12" => TextureFormat::NV12,
+            "rgb9e5ufloat" => TextureFormat::Rgb9e5Ufloat,
+            "bc1-rgba-unorm" => TextureFormat::Bc1RgbaUnorm,
+            "bc1-rgba-unorm-srgb" => TextureFormat::Bc1RgbaUnormSrgb,
+            "bc2-rgba-unorm" => TextureFormat::Bc2RgbaUnorm,
+            "bc2-rgba-unorm-srgb" => TextureFormat::Bc2RgbaUnormSrgb,
+            "bc3-rgba-unorm" => TextureFormat::Bc3RgbaUnorm,
+            "bc3-rgba-unorm-srgb" => TextureFormat::Bc3RgbaUnormSrgb,
+            "bc4-r-unorm" => TextureFormat::Bc4RUnorm,
+            "bc4-r-snorm" => TextureFormat::Bc4RSnorm,
+            "bc5-rg-unorm" => TextureFormat::Bc5RgUnorm,
+            "bc5-rg-snorm" => TextureFormat::Bc5RgSnorm,
+            "bc6h-rgb-ufloat" => TextureFormat::Bc6hRgbUfloat,
+            "bc6h-rgb-float" => TextureFormat::Bc6hRgbFloat,
+            "bc7-rgba-unorm" => TextureFormat::Bc7RgbaUnorm,
+            "bc7-rgba-unorm-srgb" => TextureFormat::Bc7RgbaUnormSrgb,
+            "etc2-rgb8unorm" => TextureFormat::Etc2Rgb8Unorm,
+            "etc2-rgb8unorm-srgb" => TextureFormat::Etc2Rgb8UnormSrgb,
+            "etc2-rgb8a1unorm" => TextureFormat::Etc2Rgb8A1Unorm,
+            "etc2-rgb8a1unorm-srgb" => TextureFormat::Etc2Rgb8A1UnormSrgb,
+            "etc2-rgba8unorm" => TextureFormat::Etc2Rgba8Unorm,
+            "etc2-rgba8unorm-srgb" => TextureFormat::Etc2Rgba8UnormSrgb,
+            "eac-r11unorm" => TextureFormat::EacR11Unorm,
+            "eac-r11snorm" => TextureFormat::EacR11Snorm,
+            "eac-rg11unorm" => TextureFormat::EacRg11Unorm,
+            "eac-rg11snorm" => TextureFormat::EacRg11Snorm,
+            other => {
+                if let Some(parts) = other.strip_prefix("astc-") {
+                    let (block, channel) = parts.split_once('-').ok_or(ParseTextureFormatError)?;
+
+                    let block = match block {
+                        "4x4" => AstcBlock::B4x4,
+                        "5x4" => AstcBlock::B5x4,
+                        "5x5" => AstcBlock::B5x5,
+                        "6x5" => AstcBlock::B6x5,
+                        "6x6" => AstcBlock::B6x6,
+                        "8x5" => AstcBlock::B8x5,
+                        "8x6" => AstcBlock::B8x6,
+                        "8x8" => AstcBlock::B8x8,
+                        "10x5" => AstcBlock::B10x5,
+                        "10x6" => AstcBlock::B10x6,
+                        "10x8" => AstcBlock::B10x8,
+                        "10x10" => AstcBlock::B10x10,
+                        "12x10" => AstcBlock::B12x10,
+                        "12x12" => AstcBlock::B12x12,
+                        _ => return Err(ParseTextureFormatError),
+                    };
+
+                    let channel = match channel {
+                        "unorm" => AstcChannel::Unorm,
+                        "unorm-srgb" => AstcChannel::UnormSrgb,
+                        "hdr" => AstcChannel::Hdr,
+                        _ => return Err(ParseTextureFormatError),
+                    };
+
+                    TextureFormat::Astc { block, channel }
+                } else {
+                    return Err(ParseTextureFormatError);
+                }
+            }
+        };
+
+        Ok(format)
+    }
+}
+
 #[cfg(any(feature = "serde", test))]
 impl<'de> Deserialize<'de> for TextureFormat {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
@@ -2853,121 +5331,8 @@ impl<'de> Deserialize<'de> for TextureFormat {
             }
 
             fn visit_str<E: Error>(self, s: &str) -> Result<Self::Value, E> {
-                let format = match s {
-                    "r8unorm" => TextureFormat::R8Unorm,
-                    "r8snorm" => TextureFormat::R8Snorm,
-                    "r8uint" => TextureFormat::R8Uint,
-                    "r8sint" => TextureFormat::R8Sint,
-                    "r16uint" => TextureFormat::R16Uint,
-                    "r16sint" => TextureFormat::R16Sint,
-                    "r16unorm" => TextureFormat::R16Unorm,
-                    "r16snorm" => TextureFormat::R16Snorm,
-                    "r16float" => TextureFormat::R16Float,
-                    "rg8unorm" => TextureFormat::Rg8Unorm,
-                    "rg8snorm" => TextureFormat::Rg8Snorm,
-                    "rg8uint" => TextureFormat::Rg8Uint,
-                    "rg8sint" => TextureFormat::Rg8Sint,
-                    "r32uint" => TextureFormat::R32Uint,
-                    "r32sint" => TextureFormat::R32Sint,
-                    "r32float" => TextureFormat::R32Float,
-                    "rg16uint" => TextureFormat::Rg16Uint,
-                    "rg16sint" => TextureFormat::Rg16Sint,
-                    "rg16unorm" => TextureFormat::Rg16Unorm,
-                    "rg16snorm" => TextureFormat::Rg16Snorm,
-                    "rg16float" => TextureFormat::Rg16Float,
-                    "rgba8unorm" => TextureFormat::Rgba8Unorm,
-                    "rgba8unorm-srgb" => TextureFormat::Rgba8UnormSrgb,
-                    "rgba8snorm" => TextureFormat::Rgba8Snorm,
-                    "rgba8uint" => TextureFormat::Rgba8Uint,
-                    "rgba8sint" => TextureFormat::Rgba8Sint,
-                    "bgra8unorm" => TextureFormat::Bgra8Unorm,
-                    "bgra8unorm-srgb" => TextureFormat::Bgra8UnormSrgb,
-                    "rgb10a2uint" => TextureFormat::Rgb10a2Uint,
-                    "rgb10a2unorm" => TextureFormat::Rgb10a2Unorm,
-                    "rg11b10ufloat" => TextureFormat::Rg11b10Ufloat,
-                    "r64uint" => TextureFormat::R64Uint,
-                    "rg32uint" => TextureFormat::Rg32Uint,
-                    "rg32sint" => TextureFormat::Rg32Sint,
-                    "rg32float" => TextureFormat::Rg32Float,
-                    "rgba16uint" => TextureFormat::Rgba16Uint,
-                    "rgba16sint" => TextureFormat::Rgba16Sint,
-                    "rgba16unorm" => TextureFormat::Rgba16Unorm,
-                    "rgba16snorm" => TextureFormat::Rgba16Snorm,
-                    "rgba16float" => TextureFormat::Rgba16Float,
-                    "rgba32uint" => TextureFormat::Rgba32Uint,
-                    "rgba32sint" => TextureFormat::Rgba32Sint,
-                    "rgba32float" => TextureFormat::Rgba32Float,
-                    "stencil8" => TextureFormat::Stencil8,
-                    "depth32float" => TextureFormat::Depth32Float,
-                    "depth32float-stencil8" => TextureFormat::Depth32FloatStencil8,
-                    "depth16unorm" => TextureFormat::Depth16Unorm,
-                    "depth24plus" => TextureFormat::Depth24Plus,
-                    "depth24plus-stencil8" => TextureFormat::Depth24PlusStencil8,
-                    "nv12" => TextureFormat::NV12,
-                    "rgb9e5ufloat" => TextureFormat::Rgb9e5Ufloat,
-                    "bc1-rgba-unorm" => TextureFormat::Bc1RgbaUnorm,
-                    "bc1-rgba-unorm-srgb" => TextureFormat::Bc1RgbaUnormSrgb,
-                    "bc2-rgba-unorm" => TextureFormat::Bc2RgbaUnorm,
-                    "bc2-rgba-unorm-srgb" => TextureFormat::Bc2RgbaUnormSrgb,
-                    "bc3-rgba-unorm" => TextureFormat::Bc3RgbaUnorm,
-                    "bc3-rgba-unorm-srgb" => TextureFormat::Bc3RgbaUnormSrgb,
-                    "bc4-r-unorm" => TextureFormat::Bc4RUnorm,
-                    "bc4-r-snorm" => TextureFormat::Bc4RSnorm,
-                    "bc5-rg-unorm" => TextureFormat::Bc5RgUnorm,
-                    "bc5-rg-snorm" => TextureFormat::Bc5RgSnorm,
-                    "bc6h-rgb-ufloat" => TextureFormat::Bc6hRgbUfloat,
-                    "bc6h-rgb-float" => TextureFormat::Bc6hRgbFloat,
-                    "bc7-rgba-unorm" => TextureFormat::Bc7RgbaUnorm,
-                    "bc7-rgba-unorm-srgb" => TextureFormat::Bc7RgbaUnormSrgb,
-                    "etc2-rgb8unorm" => TextureFormat::Etc2Rgb8Unorm,
-                    "etc2-rgb8unorm-srgb" => TextureFormat::Etc2Rgb8UnormSrgb,
-                    "etc2-rgb8a1unorm" => TextureFormat::Etc2Rgb8A1Unorm,
-                    "etc2-rgb8a1unorm-srgb" => TextureFormat::Etc2Rgb8A1UnormSrgb,
-                    "etc2-rgba8unorm" => TextureFormat::Etc2Rgba8Unorm,
-                    "etc2-rgba8unorm-srgb" => TextureFormat::Etc2Rgba8UnormSrgb,
-                    "eac-r11unorm" => TextureFormat::EacR11Unorm,
-                    "eac-r11snorm" => TextureFormat::EacR11Snorm,
-                    "eac-rg11unorm" => TextureFormat::EacRg11Unorm,
-                    "eac-rg11snorm" => TextureFormat::EacRg11Snorm,
-                    other => {
-                        if let Some(parts) = other.strip_prefix("astc-") {
-                            let (block, channel) = parts
-                                .split_once('-')
-                                .ok_or_else(|| E::invalid_value(Unexpected::Str(s), &self))?;
-
-                            let block = match block {
-                                "4x4" => AstcBlock::B4x4,
-                                "5x4" => AstcBlock::B5x4,
-                                "5x5" => AstcBlock::B5x5,
-                                "6x5" => AstcBlock::B6x5,
-                                "6x6" => AstcBlock::B6x6,
-                                "8x5" => AstcBlock::B8x5,
-                                "8x6" => AstcBlock::B8x6,
-                                "8x8" => AstcBlock::B8x8,
-                                "10x5" => AstcBlock::B10x5,
-                                "10x6" => AstcBlock::B10x6,
-                                "10x8" => AstcBlock::B10x8,
-                                "10x10" => AstcBlock::B10x10,
-                                "12x10" => AstcBlock::B12x10,
-                                "12x12" => AstcBlock::B12x12,
-                                _ => return Err(E::invalid_value(Unexpected::Str(s), &self)),
-                            };
-
-                            let channel = match channel {
-                                "unorm" => AstcChannel::Unorm,
-                                "unorm-srgb" => AstcChannel::UnormSrgb,
-                                "hdr" => AstcChannel::Hdr,
-                                _ => return Err(E::invalid_value(Unexpected::Str(s), &self)),
-                            };
-
-                            TextureFormat::Astc { block, channel }
-                        } else {
-                            return Err(E::invalid_value(Unexpected::Str(s), &self));
-                        }
-                    }
-                };
-
-                Ok(format)
+                s.parse()
+                    .map_err(|_| E::invalid_value(Unexpected::Str(s), &self))
             }
         }
 
@@ -2975,89 +5340,200 @@ impl<'de> Deserialize<'de> for TextureFormat {
     }
 }
 
-#[cfg(any(feature = "serde", test))]
-impl Serialize for TextureFormat {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: serde::Serializer,
-    {
-        let s: String;
-        let name = match *self {
-            TextureFormat::R8Unorm => "r8unorm",
-            TextureFormat::R8Snorm => "r8snorm",
-            TextureFormat::R8Uint => "r8uint",
-            TextureFormat::R8Sint => "r8sint",
-            TextureFormat::R16Uint => "r16uint",
-            TextureFormat::R16Sint => "r16sint",
-            TextureFormat::R16Unorm => "r16unorm",
-            TextureFormat::R16Snorm => "r16snorm",
-            TextureFormat::R16Float => "r16float",
-            TextureFormat::Rg8Unorm => "rg8unorm",
-            TextureFormat::Rg8Snorm => "rg8snorm",
-            TextureFormat::Rg8Uint => "rg8uint",
-            TextureFormat::Rg8Sint => "rg8sint",
-            TextureFormat::R32Uint => "r32uint",
-            TextureFormat::R32Sint => "r32sint",
-            TextureFormat::R32Float => "r32float",
-            TextureFormat::Rg16Uint => "rg16uint",
-            TextureFormat::Rg16Sint => "rg16sint",
-            TextureFormat::Rg16Unorm => "rg16unorm",
-            TextureFormat::Rg16Snorm => "rg16snorm",
-            TextureFormat::Rg16Float => "rg16float",
-            TextureFormat::Rgba8Unorm => "rgba8unorm",
-            TextureFormat::Rgba8UnormSrgb => "rgba8unorm-srgb",
-            TextureFormat::Rgba8Snorm => "rgba8snorm",
-            TextureFormat::Rgba8Uint => "rgba8uint",
-            TextureFormat::Rgba8Sint => "rgba8sint",
-            TextureFormat::Bgra8Unorm => "bgra8unorm",
-            TextureFormat::Bgra8UnormSrgb => "bgra8unorm-srgb",
-            TextureFormat::Rgb10a2Uint => "rgb10a2uint",
-            TextureFormat::Rgb10a2Unorm => "rgb10a2unorm",
-            TextureFormat::Rg11b10Ufloat => "rg11b10ufloat",
-            TextureFormat::R64Uint => "r64uint",
-            TextureFormat::Rg32Uint => "rg32uint",
-            TextureFormat::Rg32Sint => "rg32sint",
-            TextureFormat::Rg32Float => "rg32float",
-            TextureFormat::Rgba16Uint => "rgba16uint",
-            TextureFormat::Rgba16Sint => "rgba16sint",
-            TextureFormat::Rgba16Unorm => "rgba16unorm",
-            TextureFormat::Rgba16Snorm => "rgba16snorm",
-            TextureFormat::Rgba16Float => "rgba16float",
-            TextureFormat::Rgba32Uint => "rgba32uint",
-            TextureFormat::Rgba32Sint => "rgba32sint",
-            TextureFormat::Rgba32Float => "rgba32float",
-            TextureFormat::Stencil8 => "stencil8",
-            TextureFormat::Depth32Float => "depth32float",
-            TextureFormat::Depth16Unorm => "depth16unorm",
-            TextureFormat::Depth32FloatStencil8 => "depth32float-stencil8",
-            TextureFormat::Depth24Plus => "depth24plus",
-            TextureFormat::Depth24PlusStencil8 => "depth24plus-stencil8",
-            TextureFormat::NV12 => "nv12",
-            TextureFormat::Rgb9e5Ufloat => "rgb9e5ufloat",
-            TextureFormat::Bc1RgbaUnorm => "bc1-rgba-unorm",
-            TextureFormat::Bc1RgbaUnormSrgb => "bc1-rgba-unorm-srgb",
-            TextureFormat::Bc2RgbaUnorm => "bc2-rgba-unorm",
-            TextureFormat::Bc2RgbaUnormSrgb => "bc2-rgba-unorm-srgb",
-            TextureFormat::Bc3RgbaUnorm => "bc3-rgba-unorm",
-            TextureFormat::Bc3RgbaUnormSrgb => "bc3-rgba-unorm-srgb",
-            TextureFormat::Bc4RUnorm => "bc4-r-unorm",
-            TextureFormat::Bc4RSnorm => "bc4-r-snorm",
-            TextureFormat::Bc5RgUnorm => "bc5-rg-unorm",
-            TextureFormat::Bc5RgSnorm => "bc5-rg-snorm",
-            TextureFormat::Bc6hRgbUfloat => "bc6h-rgb-ufloat",
-            TextureFormat::Bc6hRgbFloat => "bc6h-rgb-float",
-            TextureFormat::Bc7RgbaUnorm => "bc7-rgba-unorm",
-            TextureFormat::Bc7RgbaUnormSrgb => "bc7-rgba-unorm-srgb",
-            TextureFormat::Etc2Rgb8Unorm => "etc2-rgb8unorm",
-            TextureFormat::Etc2Rgb8UnormSrgb => "etc2-rgb8unorm-srgb",
-            TextureFormat::Etc2Rgb8A1Unorm => "etc2-rgb8a1unorm",
-            TextureFormat::Etc2Rgb8A1UnormSrgb => "etc2-rgb8a1unorm-srgb",
-            TextureFormat::Etc2Rgba8Unorm => "etc2-rgba8unorm",
-            TextureFormat::Etc2Rgba8UnormSrgb => "etc2-rgba8unorm-srgb",
-            TextureFormat::EacR11Unorm => "eac-r11unorm",
-            TextureFormat::EacR11Snorm => "eac-r11snorm",
-            TextureFormat::EacRg11Unorm => "eac-rg11unorm",
-            TextureFormat::EacRg11Snorm => "eac-rg11snorm",
+impl TextureFormat {
+    /// Returns the name of this format, as used by its [`Display`] and [`FromStr`] impls.
+    ///
+    /// Returns `None` for [`TextureFormat::Astc`], whose name is built from its block
+    /// size and channel type and therefore has no static string.
+    ///
+    /// [`Display`]: core::fmt::Display
+    /// [`FromStr`]: core::str::FromStr
+    #[must_use]
+    pub const fn as_str(&self) -> Option<&'static str> {
+        match *self {
+            TextureFormat::R8Unorm => Some("r8unorm"),
+            TextureFormat::R8Snorm => Some("r8snorm"),
+            TextureFormat::R8Uint => Some("r8uint"),
+            TextureFormat::R8Sint => Some("r8sint"),
+            TextureFormat::R16Uint => Some("r16uint"),
+            TextureFormat::R16Sint => Some("r16sint"),
+            TextureFormat::R16Unorm => Some("r16unorm"),
+            TextureFormat::R16Snorm => Some("r16snorm"),
+            TextureFormat::R16Float => Some("r16float"),
+            TextureFormat::Rg8Unorm => Some("rg8unorm"),
+            TextureFormat::Rg8Snorm => Some("rg8snorm"),
+            TextureFormat::Rg8Uint => Some("rg8uint"),
+            TextureFormat::Rg8Sint => Some("rg8sint"),
+            TextureFormat::R32Uint => Some("r32uint"),
+            TextureFormat::R32Sint => Some("r32sint"),
+            TextureFormat::R32Float => Some("r32float"),
+            TextureFormat::Rg16Uint => Some("rg16uint"),
+            TextureFormat::Rg16Sint => Some("rg16sint"),
+            TextureFormat::Rg16Unorm => Some("rg16unorm"),
+            TextureFormat::Rg16Snorm => Some("rg16snorm"),
+            TextureFormat::Rg16Float => Some("rg16float"),
+            TextureFormat::Rgba8Unorm => Some("rgba8unorm"),
+            TextureFormat::Rgba8UnormSrgb => Some("rgba8unorm-srgb"),
+            TextureFormat::Rgba8Snorm => Some("rgba8snorm"),
+            TextureFormat::Rgba8Uint => Some("rgba8uint"),
+            TextureFormat::Rgba8Sint => Some("rgba8sint"),
+            TextureFormat::Bgra8Unorm => Some("bgra8unorm"),
+            TextureFormat::Bgra8UnormSrgb => Some("bgra8unorm-srgb"),
+            TextureFormat::Rgb10a2Uint => Some("rgb10a2uint"),
+            TextureFormat::Rgb10a2Unorm => Some("rgb10a2unorm"),
+            TextureFormat::Rg11b10Ufloat => Some("rg11b10ufloat"),
+            TextureFormat::R64Uint => Some("r64uint"),
+            TextureFormat::Rg32Uint => Some("rg32uint"),
+            TextureFormat::Rg32Sint => Some("rg32sint"),
+            TextureFormat::Rg32Float => Some("rg32float"),
+            TextureFormat::Rgba16Uint => Some("rgba16uint"),
+            TextureFormat::Rgba16Sint => Some("rgba16sint"),
+            TextureFormat::Rgba16Unorm => Some("rgba16unorm"),
+            TextureFormat::Rgba16Snorm => Some("rgba16snorm"),
+            TextureFormat::Rgba16Float => Some("rgba16float"),
+            TextureFormat::Rgba32Uint => Some("rgba32uint"),
+            TextureFormat::Rgba32Sint => Some("rgba32sint"),
+            TextureFormat::Rgba32Float => Some("rgba32float"),
+            TextureFormat::Stencil8 => Some("stencil8"),
+            TextureFormat::Depth32Float => Some("depth32float"),
+            TextureFormat::Depth16Unorm => Some("depth16unorm"),
+            TextureFormat::Depth32FloatStencil8 => Some("depth32float-stencil8"),
+            TextureFormat::Depth24Plus => Some("depth24plus"),
+            TextureFormat::Depth24PlusStencil8 => Some("depth24plus-stencil8"),
+            TextureFormat::NV12 => Some("nv12"),
+            TextureFormat::Rgb9e5Ufloat => Some("rgb9e5ufloat"),
+            TextureFormat::Bc1RgbaUnorm => Some("bc1-rgba-unorm"),
+            TextureFormat::Bc1RgbaUnormSrgb => Some("bc1-rgba-unorm-srgb"),
+            TextureFormat::Bc2RgbaUnorm => Some("bc2-rgba-unorm"),
+            TextureFormat::Bc2RgbaUnormSrgb => Some("bc2-rgba-unorm-srgb"),
+            TextureFormat::Bc3RgbaUnorm => Some("bc3-rgba-unorm"),
+            TextureFormat::Bc3RgbaUnormSrgb => Some("bc3-rgba-unorm-srgb"),
+            TextureFormat::Bc4RUnorm => Some("bc4-r-unorm"),
+            TextureFormat::Bc4RSnorm => Some("bc4-r-snorm"),
+            TextureFormat::Bc5RgUnorm => Some("bc5-rg-unorm"),
+            TextureFormat::Bc5RgSnorm => Some("bc5-rg-snorm"),
+            TextureFormat::Bc6hRgbUfloat => Some("bc6h-rgb-ufloat"),
+            TextureFormat::Bc6hRgbFloat => Some("bc6h-rgb-float"),
+            TextureFormat::Bc7RgbaUnorm => Some("bc7-rgba-unorm"),
+            TextureFormat::Bc7RgbaUnormSrgb => Some("bc7-rgba-unorm-srgb"),
+            TextureFormat::Etc2Rgb8Unorm => Some("etc2-rgb8unorm"),
+            TextureFormat::Etc2Rgb8UnormSrgb => Some("etc2-rgb8unorm-srgb"),
+            TextureFormat::Etc2Rgb8A1Unorm => Some("etc2-rgb8a1unorm"),
+            TextureFormat::Etc2Rgb8A1UnormSrgb => Some("etc2-rgb8a1unorm-srgb"),
+            TextureFormat::Etc2Rgba8Unorm => Some("etc2-rgba8unorm"),
+            TextureFormat::Etc2Rgba8UnormSrgb => Some("etc2-rgba8unorm-srgb"),
+            TextureFormat::EacR11Unorm => Some("eac-r11unorm"),
+            TextureFormat::EacR11Snorm => Some("eac-r11snorm"),
+            TextureFormat::EacRg11Unorm => Some("eac-rg11unorm"),
+            TextureFormat::EacRg11Snorm => Some("eac-rg11snorm"),
+            TextureFormat::Astc { .. } => None,
+        }
+    }
+
+    /// Every format other than [`TextureFormat::Astc`].
+    const NON_ASTC: [Self; 75] = [
+        Self::R8Unorm,
+        Self::R8Snorm,
+        Self::R8Uint,
+        Self::R8Sint,
+        Self::R16Uint,
+        Self::R16Sint,
+        Self::R16Unorm,
+        Self::R16Snorm,
+        Self::R16Float,
+        Self::Rg8Unorm,
+        Self::Rg8Snorm,
+        Self::Rg8Uint,
+        Self::Rg8Sint,
+        Self::R32Uint,
+        Self::R32Sint,
+        Self::R32Float,
+        Self::Rg16Uint,
+        Self::Rg16Sint,
+        Self::Rg16Unorm,
+        Self::Rg16Snorm,
+        Self::Rg16Float,
+        Self::Rgba8Unorm,
+        Self::Rgba8UnormSrgb,
+        Self::Rgba8Snorm,
+        Self::Rgba8Uint,
+        Self::Rgba8Sint,
+        Self::Bgra8Unorm,
+        Self::Bgra8UnormSrgb,
+        Self::Rgb9e5Ufloat,
+        Self::Rgb10a2Uint,
+        Self::Rgb10a2Unorm,
+        Self::Rg11b10Ufloat,
+        Self::R64Uint,
+        Self::Rg32Uint,
+        Self::Rg32Sint,
+        Self::Rg32Float,
+        Self::Rgba16Uint,
+        Self::Rgba16Sint,
+        Self::Rgba16Unorm,
+        Self::Rgba16Snorm,
+        Self::Rgba16Float,
+        Self::Rgba32Uint,
+        Self::Rgba32Sint,
+        Self::Rgba32Float,
+        Self::Stencil8,
+        Self::Depth16Unorm,
+        Self::Depth24Plus,
+        Self::Depth24PlusStencil8,
+        Self::Depth32Float,
+        Self::Depth32FloatStencil8,
+        Self::NV12,
+        Self::Bc1RgbaUnorm,
+        Self::Bc1RgbaUnormSrgb,
+        Self::Bc2RgbaUnorm,
+        Self::Bc2RgbaUnormSrgb,
+        Self::Bc3RgbaUnorm,
+        Self::Bc3RgbaUnormSrgb,
+        Self::Bc4RUnorm,
+        Self::Bc4RSnorm,
+        Self::Bc5RgUnorm,
+        Self::Bc5RgSnorm,
+        Self::Bc6hRgbUfloat,
+        Self::Bc6hRgbFloat,
+        Self::Bc7RgbaUnorm,
+        Self::Bc7RgbaUnormSrgb,
+        Self::Etc2Rgb8Unorm,
+        Self::Etc2Rgb8UnormSrgb,
+        Self::Etc2Rgb8A1Unorm,
+        Self::Etc2Rgb8A1UnormSrgb,
+        Self::Etc2Rgba8Unorm,
+        Self::Etc2Rgba8UnormSrgb,
+        Self::EacR11Unorm,
+        Self::EacR11Snorm,
+        Self::EacRg11Unorm,
+        Self::EacRg11Snorm,
+    ];
+
+    /// Returns an iterator over every format, in declaration order.
+    ///
+    /// This is [`Self::all_non_astc`] followed by [`Self::astc_variants`].
+    pub fn all() -> impl Iterator<Item = Self> {
+        Self::all_non_astc().chain(Self::astc_variants())
+    }
+
+    /// Returns an iterator over every format other than [`TextureFormat::Astc`].
+    pub fn all_non_astc() -> impl Iterator<Item = Self> {
+        Self::NON_ASTC.into_iter()
+    }
+
+    /// Returns an iterator over every [`TextureFormat::Astc`] block and channel combination.
+    pub fn astc_variants() -> impl Iterator<Item = Self> {
+        AstcBlock::ALL.into_iter().flat_map(|block| {
+            AstcChannel::ALL
+                .into_iter()
+                .map(move |channel| Self::Astc { block, channel })
+        })
+    }
+}
+
+impl core::fmt::Display for TextureFormat {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match *self {
             TextureFormat::Astc { block, channel } => {
                 let block = match block {
                     AstcBlock::B4x4 => "4x4",
@@ -3082,7 +5558,26 @@ impl Serialize for TextureFormat {
                     AstcChannel::Hdr => "hdr",
                 };
 
-                s = format!("astc-{block}-{channel}");
+                write!(f, "astc-{block}-{channel}")
+            }
+            _ => f.write_str(self.as_str().unwrap()),
+        }
+    }
+}
+
+#[cfg(any(feature = "serde", test))]
+impl Serialize for TextureFormat {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use alloc::string::ToString;
+
+        let s: String;
+        let name = match self.as_str() {
+            Some(name) => name,
+            None => {
+                s = self.to_string();
                 &s
             }
         };
@@ -3129,6 +5624,16 @@ impl TextureFormat {
         }
     }
 
+    /// Returns `true` if a view of the given `aspect` can be created of a texture of this format,
+    /// that is, if [`Self::aspect_specific_format`] returns `Some`.
+    ///
+    /// Multi-planar formats require a plane aspect, and depth/stencil formats only allow
+    /// the aspects they contain.
+    #[must_use]
+    pub fn is_valid_aspect(&self, aspect: TextureAspect) -> bool {
+        self.aspect_specific_format(aspect).is_some()
+    }
+
     /// Returns `true` if `self` is a depth or stencil component of the given
     /// combined depth-stencil format
     #[must_use]
@@ -3210,6 +5715,48 @@ impl TextureFormat {
         }
     }
 
+    /// Returns the guaranteed number of bits of precision of the depth aspect, if the format
+    /// has one.
+    ///
+    /// The `Depth24Plus` formats may be backed by a format with more precision, such as
+    /// [`Self::Depth32Float`], so this is a lower bound for them.
+    #[must_use]
+    pub fn depth_bits(&self) -> Option<u32> {
+        match *self {
+            Self::Depth16Unorm => Some(16),
+            Self::Depth24Plus | Self::Depth24PlusStencil8 => Some(24),
+            Self::Depth32Float | Self::Depth32FloatStencil8 => Some(32),
+            _ => None,
+        }
+    }
+
+    /// Returns the number of bits of the stencil aspect, if the format has one.
+    #[must_use]
+    pub fn stencil_bits(&self) -> Option<u32> {
+        match *self {
+            Self::Stencil8 | Self::Depth24PlusStencil8 | Self::Depth32FloatStencil8 => Some(8),
+            _ => None,
+        }
+    }
+
+    /// Returns the set of aspects present in the format.
+    ///
+    /// Multi-planar formats report one aspect per plane, rather than a color aspect.
+    #[must_use]
+    pub fn aspects(&self) -> TextureAspectFlags {
+        match *self {
+            Self::Stencil8 => TextureAspectFlags::STENCIL,
+            Self::Depth16Unorm | Self::Depth24Plus | Self::Depth32Float => {
+                TextureAspectFlags::DEPTH
+            }
+            Self::Depth24PlusStencil8 | Self::Depth32FloatStencil8 => {
+                TextureAspectFlags::DEPTH_STENCIL
+            }
+            Self::NV12 => TextureAspectFlags::PLANE_0 | TextureAspectFlags::PLANE_1,
+            _ => TextureAspectFlags::COLOR,
+        }
+    }
+
     /// Returns the size multiple requirement for a texture using this format.
     #[must_use]
     pub fn size_multiple_requirement(&self) -> (u32, u32) {
@@ -3322,6 +5869,18 @@ impl TextureFormat {
         }
     }
 
+    /// Returns the width, height and depth of a [block](https://gpuweb.github.io/gpuweb/#texel-block) of texels.
+    ///
+    /// The width and height are those of [`Self::block_dimensions`]. The depth of a block is
+    /// currently always 1, including for BCn formats in 3D textures (see
+    /// [`Features::TEXTURE_COMPRESSION_BC_SLICED_3D`]), but this may change if formats with
+    /// 3D blocks are added.
+    #[must_use]
+    pub fn block_dimensions_3d(&self) -> (u32, u32, u32) {
+        let (width, height) = self.block_dimensions();
+        (width, height, 1)
+    }
+
     /// Returns `true` for compressed formats.
     #[must_use]
     pub fn is_compressed(&self) -> bool {
@@ -3427,6 +5986,43 @@ impl TextureFormat {
         }
     }
 
+    /// Returns a format with the same channels as this one that can be sampled with a
+    /// filtering sampler on a device with the given features.
+    ///
+    /// Returns `Some(self)` if the format is already filterable, and the lower precision
+    /// `16Float` counterpart of the `32Float` formats if [`Features::FLOAT32_FILTERABLE`] is
+    /// not enabled. Returns `None` if there is no such format, which is the case for integer
+    /// and depth/stencil formats.
+    #[must_use]
+    pub fn filterable_alternative(&self, device_features: Features) -> Option<Self> {
+        match self.sample_type(None, Some(device_features)) {
+            Some(TextureSampleType::Float { filterable: true }) => Some(*self),
+            Some(TextureSampleType::Float { filterable: false }) => match *self {
+                Self::R32Float => Some(Self::R16Float),
+                Self::Rg32Float => Some(Self::Rg16Float),
+                Self::Rgba32Float => Some(Self::Rgba16Float),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Classifies the format on a device with the given features, see [`FormatClassification`].
+    #[must_use]
+    pub fn classification(&self, device_features: Features) -> FormatClassification {
+        FormatClassification {
+            color: self.has_color_aspect(),
+            depth: self.has_depth_aspect(),
+            stencil: self.has_stencil_aspect(),
+            compressed: self.is_compressed(),
+            srgb: self.is_srgb(),
+            filterable: matches!(
+                self.sample_type(None, Some(device_features)),
+                Some(TextureSampleType::Float { filterable: true })
+            ),
+        }
+    }
+
     /// Returns the format features guaranteed by the WebGPU spec.
     ///
     /// Additional features are available if `Features::TEXTURE_ADAPTER_SPECIFIC_FORMAT_FEATURES` is enabled.
@@ -3580,6 +6176,30 @@ impl TextureFormat {
         }
     }
 
+    /// Returns true if a texture of this format can be used as a render attachment, together
+    /// with the other given `usages`.
+    ///
+    /// This only reflects the features guaranteed by [`Self::guaranteed_format_features`] for
+    /// the given device `features`, adapters may support more.
+    #[must_use]
+    pub fn is_renderable(&self, usages: TextureUsages, device_features: Features) -> bool {
+        self.guaranteed_format_features(device_features)
+            .allowed_usages
+            .contains(usages | TextureUsages::RENDER_ATTACHMENT)
+    }
+
+    /// Returns true if a texture of this format can be bound as a storage texture.
+    ///
+    /// Note that storage textures can't be multisampled. This only reflects the features
+    /// guaranteed by [`Self::guaranteed_format_features`] for the given device `features`,
+    /// adapters may support more.
+    #[must_use]
+    pub fn supports_storage(&self, device_features: Features) -> bool {
+        self.guaranteed_format_features(device_features)
+            .allowed_usages
+            .contains(TextureUsages::STORAGE_BINDING)
+    }
+
     /// Returns the sample type compatible with this format and aspect.
     ///
     /// Returns `None` only if this is a combined depth-stencil format or a multi-planar format
@@ -3811,6 +6431,59 @@ impl TextureFormat {
         }
     }
 
+    /// The number of bytes in one row of texel blocks covering `width` texels, during an image
+    /// copy of the given `aspect`.
+    ///
+    /// This is the tightly packed size, see [`Self::padded_bytes_per_row`] for a size suitable
+    /// for buffer-texture copies.
+    ///
+    /// Returns `None` if [`Self::block_copy_size`] does, or if the result overflows.
+    #[must_use]
+    pub fn bytes_per_row(&self, width: u32, aspect: TextureAspect) -> Option<u32> {
+        let block_size = self.block_copy_size(Some(aspect))?;
+        let (block_width, _) = self.block_dimensions();
+        width.div_ceil(block_width).checked_mul(block_size)
+    }
+
+    /// The result of [`Self::bytes_per_row`], rounded up to [`COPY_BYTES_PER_ROW_ALIGNMENT`].
+    ///
+    /// This is the smallest valid [`TexelCopyBufferLayout::bytes_per_row`] for copying
+    /// `width` texels between a buffer and a texture.
+    #[must_use]
+    pub fn padded_bytes_per_row(&self, width: u32, aspect: TextureAspect) -> Option<u32> {
+        self.bytes_per_row(width, aspect)?
+            .checked_next_multiple_of(COPY_BYTES_PER_ROW_ALIGNMENT)
+    }
+
+    /// Estimates the number of bytes occupied by the given `aspect` of a texture of this format
+    /// with `mip_levels` mip levels, across all array layers.
+    ///
+    /// Sums the [physical size](Extent3d::physical_size) of each
+    /// [mip level](Extent3d::mip_level_size), in texel blocks, times [`Self::block_copy_size`].
+    /// This is only an estimate: it ignores any alignment padding or compression the
+    /// implementation may apply.
+    ///
+    /// Returns `None` if [`Self::block_copy_size`] does for `aspect`, or if the result overflows.
+    #[must_use]
+    pub fn estimated_texture_memory(
+        &self,
+        size: Extent3d,
+        dim: TextureDimension,
+        mip_levels: u32,
+        aspect: TextureAspect,
+    ) -> Option<u64> {
+        let block_size = u64::from(self.block_copy_size(Some(aspect))?);
+        let (block_width, block_height) = self.block_dimensions();
+
+        (0..mip_levels).try_fold(0u64, |total, level| {
+            let extent = size.mip_level_size(level, dim).physical_size(*self);
+            let blocks = u64::from(extent.width / block_width)
+                * u64::from(extent.height / block_height)
+                * u64::from(extent.depth_or_array_layers);
+            total.checked_add(blocks.checked_mul(block_size)?)
+        })
+    }
+
     /// The largest number that can be returned by [`Self::target_pixel_byte_cost`].
     pub const MAX_TARGET_PIXEL_BYTE_COST: u32 = 16;
 
@@ -4122,11 +6795,179 @@ impl TextureFormat {
         }
     }
 
-    /// Returns `true` for srgb formats.
-    #[must_use]
-    pub fn is_srgb(&self) -> bool {
-        *self != self.remove_srgb_suffix()
-    }
+    /// Returns `true` for srgb formats.
+    #[must_use]
+    pub fn is_srgb(&self) -> bool {
+        *self != self.remove_srgb_suffix()
+    }
+
+    /// Returns the linear (non-srgb) form of the format, and its srgb form if it has one.
+    ///
+    /// This is the same for both forms of a format, which is useful when building lists of
+    /// view formats which only differ in srgb-ness.
+    #[must_use]
+    pub fn srgb_pair(&self) -> (TextureFormat, Option<TextureFormat>) {
+        let linear = self.remove_srgb_suffix();
+        let srgb = linear.add_srgb_suffix();
+        (linear, (srgb != linear).then_some(srgb))
+    }
+
+    /// Returns `true` if a texture of this format can be copied to or from a texture of the
+    /// `other` format with `copy_texture_to_texture`.
+    ///
+    /// Following the [WebGPU copy-compatibility rules](https://gpuweb.github.io/gpuweb/#copy-compatible),
+    /// formats are compatible if they are identical or only differ in srgb-ness.
+    #[must_use]
+    pub fn copy_compatible_with(&self, other: TextureFormat) -> bool {
+        self.remove_srgb_suffix() == other.remove_srgb_suffix()
+    }
+}
+
+#[test]
+fn texture_format_copy_compatible_with() {
+    use TextureFormat as Tf;
+
+    assert!(Tf::Rgba8Unorm.copy_compatible_with(Tf::Rgba8Unorm));
+    assert!(Tf::Rgba8Unorm.copy_compatible_with(Tf::Rgba8UnormSrgb));
+    assert!(Tf::Rgba8UnormSrgb.copy_compatible_with(Tf::Rgba8Unorm));
+    assert!(!Tf::Rgba8Unorm.copy_compatible_with(Tf::Rgba16Float));
+    assert!(!Tf::Rgba8Unorm.copy_compatible_with(Tf::Bgra8Unorm));
+    assert!(Tf::Depth24PlusStencil8.copy_compatible_with(Tf::Depth24PlusStencil8));
+}
+
+/// Summary of the properties of a [`TextureFormat`].
+///
+/// Returned by [`TextureFormat::classification`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct FormatClassification {
+    /// The format has a color aspect, see [`TextureFormat::has_color_aspect`].
+    pub color: bool,
+    /// The format has a depth aspect, see [`TextureFormat::has_depth_aspect`].
+    pub depth: bool,
+    /// The format has a stencil aspect, see [`TextureFormat::has_stencil_aspect`].
+    pub stencil: bool,
+    /// The format is block compressed, see [`TextureFormat::is_compressed`].
+    pub compressed: bool,
+    /// The format is srgb, see [`TextureFormat::is_srgb`].
+    pub srgb: bool,
+    /// The format can be sampled with a filtering sampler on a device with the features
+    /// passed to [`TextureFormat::classification`].
+    pub filterable: bool,
+}
+
+#[test]
+fn texture_format_is_valid_aspect() {
+    use TextureAspect as A;
+
+    let depth_stencil = TextureFormat::Depth24PlusStencil8;
+    assert!(depth_stencil.is_valid_aspect(A::All));
+    assert!(depth_stencil.is_valid_aspect(A::DepthOnly));
+    assert!(depth_stencil.is_valid_aspect(A::StencilOnly));
+    assert!(!depth_stencil.is_valid_aspect(A::Plane0));
+
+    assert!(TextureFormat::Depth32Float.is_valid_aspect(A::DepthOnly));
+    assert!(!TextureFormat::Depth32Float.is_valid_aspect(A::StencilOnly));
+
+    let nv12 = TextureFormat::NV12;
+    assert!(!nv12.is_valid_aspect(A::All));
+    assert!(nv12.is_valid_aspect(A::Plane0));
+    assert!(nv12.is_valid_aspect(A::Plane1));
+    assert!(!nv12.is_valid_aspect(A::Plane2));
+
+    let color = TextureFormat::Rgba8Unorm;
+    assert!(color.is_valid_aspect(A::All));
+    assert!(!color.is_valid_aspect(A::DepthOnly));
+    assert!(!color.is_valid_aspect(A::Plane0));
+}
+
+#[test]
+fn texture_format_classification() {
+    let features = Features::empty();
+    assert_eq!(
+        TextureFormat::Bgra8UnormSrgb.classification(features),
+        FormatClassification {
+            color: true,
+            srgb: true,
+            filterable: true,
+            ..Default::default()
+        }
+    );
+    assert_eq!(
+        TextureFormat::Depth24PlusStencil8.classification(features),
+        FormatClassification {
+            depth: true,
+            stencil: true,
+            ..Default::default()
+        }
+    );
+    assert_eq!(
+        TextureFormat::Bc1RgbaUnorm.classification(features),
+        FormatClassification {
+            color: true,
+            compressed: true,
+            filterable: true,
+            ..Default::default()
+        }
+    );
+    assert!(!TextureFormat::R32Float.classification(features).filterable);
+    assert!(
+        TextureFormat::R32Float
+            .classification(Features::FLOAT32_FILTERABLE)
+            .filterable
+    );
+    assert!(!TextureFormat::R32Uint.classification(features).filterable);
+}
+
+#[test]
+fn texture_format_srgb_pair() {
+    let pair = (
+        TextureFormat::Bgra8Unorm,
+        Some(TextureFormat::Bgra8UnormSrgb),
+    );
+    assert_eq!(TextureFormat::Bgra8Unorm.srgb_pair(), pair);
+    assert_eq!(TextureFormat::Bgra8UnormSrgb.srgb_pair(), pair);
+    assert_eq!(
+        TextureFormat::R8Unorm.srgb_pair(),
+        (TextureFormat::R8Unorm, None)
+    );
+}
+
+#[test]
+fn texture_format_estimated_memory() {
+    use TextureDimension as D;
+
+    let size = |width, height, depth_or_array_layers| Extent3d {
+        width,
+        height,
+        depth_or_array_layers,
+    };
+    let estimate = |format: TextureFormat, size, dim, mips, aspect| {
+        format.estimated_texture_memory(size, dim, mips, aspect)
+    };
+    let all = TextureAspect::All;
+
+    let rgba = TextureFormat::Rgba8Unorm;
+    assert_eq!(estimate(rgba, size(4, 4, 1), D::D2, 3, all), Some(84));
+    assert_eq!(estimate(rgba, size(4, 4, 6), D::D2, 1, all), Some(384));
+    assert_eq!(estimate(rgba, size(4, 4, 4), D::D3, 2, all), Some(288));
+    assert_eq!(estimate(rgba, size(4, 4, 1), D::D2, 0, all), Some(0));
+
+    // Small mips are padded to whole blocks.
+    let bc1 = TextureFormat::Bc1RgbaUnorm;
+    assert_eq!(estimate(bc1, size(8, 8, 1), D::D2, 4, all), Some(56));
+
+    let depth_stencil = TextureFormat::Depth24PlusStencil8;
+    assert_eq!(estimate(depth_stencil, size(4, 4, 1), D::D2, 1, all), None);
+    assert_eq!(
+        estimate(
+            depth_stencil,
+            size(4, 4, 1),
+            D::D2,
+            1,
+            TextureAspect::StencilOnly
+        ),
+        Some(16)
+    );
 }
 
 #[test]
@@ -4731,6 +7572,182 @@ fn texture_format_deserialize() {
     );
 }
 
+#[test]
+fn texture_format_serde_round_trip() {
+    for format in TextureFormat::all() {
+        let json = serde_json::to_string(&format).unwrap();
+        assert_eq!(
+            serde_json::from_str::<TextureFormat>(&json).unwrap(),
+            format
+        );
+    }
+}
+
+#[test]
+fn texture_format_from_str() {
+    assert_eq!("r8unorm".parse(), Ok(TextureFormat::R8Unorm));
+    assert_eq!("bgra8unorm-srgb".parse(), Ok(TextureFormat::Bgra8UnormSrgb));
+    assert_eq!(
+        "astc-10x8-unorm-srgb".parse(),
+        Ok(TextureFormat::Astc {
+            block: AstcBlock::B10x8,
+            channel: AstcChannel::UnormSrgb,
+        })
+    );
+    assert_eq!(
+        "astc-10x8".parse::<TextureFormat>(),
+        Err(ParseTextureFormatError)
+    );
+    assert_eq!(
+        "astc-3x3-hdr".parse::<TextureFormat>(),
+        Err(ParseTextureFormatError)
+    );
+    assert_eq!(
+        "RGBA8Unorm".parse::<TextureFormat>(),
+        Err(ParseTextureFormatError)
+    );
+    assert_eq!("".parse::<TextureFormat>(), Err(ParseTextureFormatError));
+}
+
+#[test]
+fn texture_format_display() {
+    use alloc::string::ToString;
+
+    assert_eq!(
+        TextureFormat::Rgba8UnormSrgb.as_str(),
+        Some("rgba8unorm-srgb")
+    );
+    assert_eq!(
+        TextureFormat::Bc7RgbaUnormSrgb.to_string(),
+        "bc7-rgba-unorm-srgb"
+    );
+
+    let astc = TextureFormat::Astc {
+        block: AstcBlock::B12x10,
+        channel: AstcChannel::Hdr,
+    };
+    assert_eq!(astc.as_str(), None);
+    assert_eq!(astc.to_string(), "astc-12x10-hdr");
+    assert_eq!(astc.to_string().parse(), Ok(astc));
+
+    for format in TextureFormat::all() {
+        assert_eq!(format.to_string().parse(), Ok(format));
+    }
+}
+
+#[test]
+fn texture_format_aspects() {
+    assert_eq!(
+        TextureFormat::Depth24PlusStencil8.aspects(),
+        TextureAspectFlags::DEPTH | TextureAspectFlags::STENCIL
+    );
+    assert_eq!(
+        TextureFormat::Rgba8Unorm.aspects(),
+        TextureAspectFlags::COLOR
+    );
+    assert_eq!(
+        TextureFormat::NV12.aspects(),
+        TextureAspectFlags::PLANE_0 | TextureAspectFlags::PLANE_1
+    );
+    for format in TextureFormat::all() {
+        let aspects = format.aspects();
+        assert_eq!(
+            aspects.contains(TextureAspectFlags::DEPTH),
+            format.has_depth_aspect()
+        );
+        assert_eq!(
+            aspects.contains(TextureAspectFlags::STENCIL),
+            format.has_stencil_aspect()
+        );
+    }
+}
+
+#[test]
+fn texture_format_depth_stencil_bits() {
+    use TextureFormat as F;
+
+    let bits = |format: F| (format.depth_bits(), format.stencil_bits());
+    assert_eq!(bits(F::Depth16Unorm), (Some(16), None));
+    assert_eq!(bits(F::Depth24Plus), (Some(24), None));
+    assert_eq!(bits(F::Depth24PlusStencil8), (Some(24), Some(8)));
+    assert_eq!(bits(F::Depth32Float), (Some(32), None));
+    assert_eq!(bits(F::Depth32FloatStencil8), (Some(32), Some(8)));
+    assert_eq!(bits(F::Stencil8), (None, Some(8)));
+    assert_eq!(bits(F::Rgba8Unorm), (None, None));
+}
+
+#[test]
+fn texture_format_filterable_alternative() {
+    let none = Features::empty();
+    assert_eq!(
+        TextureFormat::Rgba8Unorm.filterable_alternative(none),
+        Some(TextureFormat::Rgba8Unorm)
+    );
+    assert_eq!(
+        TextureFormat::Rg32Float.filterable_alternative(none),
+        Some(TextureFormat::Rg16Float)
+    );
+    assert_eq!(
+        TextureFormat::Rg32Float.filterable_alternative(Features::FLOAT32_FILTERABLE),
+        Some(TextureFormat::Rg32Float)
+    );
+    assert_eq!(TextureFormat::R32Uint.filterable_alternative(none), None);
+    assert_eq!(
+        TextureFormat::Depth32Float.filterable_alternative(none),
+        None
+    );
+}
+
+#[test]
+fn texture_format_allowed_usages() {
+    let none = Features::empty();
+    assert!(TextureFormat::Rgba8Unorm.is_renderable(TextureUsages::TEXTURE_BINDING, none));
+    assert!(!TextureFormat::Rgba8UnormSrgb.is_renderable(TextureUsages::STORAGE_BINDING, none));
+    assert!(!TextureFormat::Rgba8Snorm.is_renderable(TextureUsages::empty(), none));
+    assert!(!TextureFormat::Bc1RgbaUnorm.is_renderable(TextureUsages::empty(), none));
+
+    assert!(TextureFormat::Rgba8Unorm.supports_storage(none));
+    assert!(!TextureFormat::Bgra8Unorm.supports_storage(none));
+    assert!(TextureFormat::Bgra8Unorm.supports_storage(Features::BGRA8UNORM_STORAGE));
+}
+
+#[test]
+fn texture_format_bytes_per_row() {
+    let all = TextureAspect::All;
+
+    assert_eq!(TextureFormat::Rgba8Unorm.bytes_per_row(100, all), Some(400));
+    assert_eq!(
+        TextureFormat::Rgba8Unorm.padded_bytes_per_row(100, all),
+        Some(512)
+    );
+    // 8 bytes per 4x4 block, partial blocks are rounded up.
+    assert_eq!(TextureFormat::Bc1RgbaUnorm.bytes_per_row(13, all), Some(32));
+    assert_eq!(TextureFormat::Bc1RgbaUnorm.bytes_per_row(1, all), Some(8));
+    // 16 bytes per 4x4 block.
+    assert_eq!(
+        TextureFormat::Bc7RgbaUnorm.bytes_per_row(65, all),
+        Some(272)
+    );
+    assert_eq!(
+        TextureFormat::Bc7RgbaUnorm.padded_bytes_per_row(65, all),
+        Some(512)
+    );
+    assert_eq!(
+        TextureFormat::Bc7RgbaUnorm.padded_bytes_per_row(0, all),
+        Some(0)
+    );
+
+    assert_eq!(TextureFormat::Depth24Plus.bytes_per_row(64, all), None);
+    assert_eq!(
+        TextureFormat::Depth32FloatStencil8.bytes_per_row(64, TextureAspect::StencilOnly),
+        Some(64)
+    );
+    assert_eq!(
+        TextureFormat::Rgba32Float.bytes_per_row(u32::MAX, all),
+        None
+    );
+}
+
 /// Color write mask. Disabled color channels will not be written to.
 ///
 /// Corresponds to [WebGPU `GPUColorWriteFlags`](
@@ -4764,6 +7781,63 @@ impl Default for ColorWrites {
     }
 }
 
+impl ColorWrites {
+    /// Returns the mask that writes exactly the first `count` channels, in RGBA order.
+    ///
+    /// This is useful to match the number of components written by a fragment shader output.
+    /// Counts above 4 are treated as 4.
+    #[must_use]
+    pub const fn from_channel_count(count: u32) -> Self {
+        match count {
+            0 => Self::empty(),
+            1 => Self::RED,
+            2 => Self::RED.union(Self::GREEN),
+            3 => Self::COLOR,
+            _ => Self::ALL,
+        }
+    }
+
+    /// Returns true if the red channel is written.
+    #[must_use]
+    pub const fn writes_red(&self) -> bool {
+        self.contains(Self::RED)
+    }
+
+    /// Returns true if the green channel is written.
+    #[must_use]
+    pub const fn writes_green(&self) -> bool {
+        self.contains(Self::GREEN)
+    }
+
+    /// Returns true if the blue channel is written.
+    #[must_use]
+    pub const fn writes_blue(&self) -> bool {
+        self.contains(Self::BLUE)
+    }
+
+    /// Returns true if the alpha channel is written.
+    #[must_use]
+    pub const fn writes_alpha(&self) -> bool {
+        self.contains(Self::ALPHA)
+    }
+}
+
+#[test]
+fn color_writes_from_channel_count() {
+    assert_eq!(ColorWrites::from_channel_count(0), ColorWrites::empty());
+    assert_eq!(
+        ColorWrites::from_channel_count(2),
+        ColorWrites::RED | ColorWrites::GREEN
+    );
+    assert_eq!(ColorWrites::from_channel_count(3), ColorWrites::COLOR);
+    assert_eq!(ColorWrites::from_channel_count(4), ColorWrites::ALL);
+    assert_eq!(ColorWrites::from_channel_count(7), ColorWrites::ALL);
+
+    let rgb = ColorWrites::from_channel_count(3);
+    assert!(rgb.writes_red() && rgb.writes_green() && rgb.writes_blue());
+    assert!(!rgb.writes_alpha());
+}
+
 /// Passed to `Device::poll` to control how and if it should block.
 #[derive(Clone, Debug)]
 pub enum Maintain<T> {
@@ -4819,9 +7893,53 @@ impl<T> Maintain<T> {
             Self::Poll => Maintain::Poll,
         }
     }
+
+    /// Converts from `&Maintain<T>` to `Maintain<&T>`, analogous to [`Option::as_ref`].
+    #[must_use]
+    pub fn as_ref(&self) -> Maintain<&T> {
+        match *self {
+            Self::WaitForSubmissionIndex(ref i) => Maintain::WaitForSubmissionIndex(i),
+            Self::Wait => Maintain::Wait,
+            Self::Poll => Maintain::Poll,
+        }
+    }
+
+    /// Returns the kind of this maintain, without the submission index.
+    #[must_use]
+    pub fn kind(&self) -> MaintainKind {
+        match *self {
+            Self::WaitForSubmissionIndex(..) => MaintainKind::WaitForIndex,
+            Self::Wait => MaintainKind::Wait,
+            Self::Poll => MaintainKind::Poll,
+        }
+    }
+}
+
+/// The kind of a [`Maintain`], as returned by [`Maintain::kind`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MaintainKind {
+    /// [`Maintain::WaitForSubmissionIndex`].
+    WaitForIndex,
+    /// [`Maintain::Wait`].
+    Wait,
+    /// [`Maintain::Poll`].
+    Poll,
+}
+
+#[test]
+fn maintain_kind() {
+    let maintain = Maintain::wait_for(alloc::string::String::from("index"));
+    assert_eq!(maintain.kind(), MaintainKind::WaitForIndex);
+    assert!(matches!(
+        maintain.as_ref().map_index(|i| i.len()),
+        Maintain::WaitForSubmissionIndex(5)
+    ));
+    assert_eq!(Maintain::<()>::Wait.kind(), MaintainKind::Wait);
+    assert_eq!(Maintain::<()>::Poll.as_ref().kind(), MaintainKind::Poll);
 }
 
 /// Result of a maintain operation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum MaintainResult {
     /// There are no active submissions in flight as of the beginning of the poll call.
     /// Other submissions may have been queued on other threads at the same time.
@@ -4830,21 +7948,83 @@ pub enum MaintainResult {
     SubmissionQueueEmpty,
     /// More information coming soon <https://github.com/gfx-rs/wgpu/pull/5012>
     Ok,
+    /// The wait timed out before the requested submissions completed.
+    ///
+    /// Submissions may still be in flight, and the device may be stalled.
+    Timeout,
 }
 
 impl MaintainResult {
+    /// Creates the result of a maintain operation.
+    ///
+    /// `wait_completed` is false if waiting for the submissions timed out, and is true if the
+    /// wait completed or if no wait was requested. A timeout takes precedence over
+    /// `queue_empty`, as the queue may still be busy with the submissions that were waited on.
+    #[must_use]
+    pub fn from_wait(wait_completed: bool, queue_empty: bool) -> Self {
+        match (wait_completed, queue_empty) {
+            (false, _) => Self::Timeout,
+            (true, true) => Self::SubmissionQueueEmpty,
+            (true, false) => Self::Ok,
+        }
+    }
+
     /// Returns true if the result is [`Self::SubmissionQueueEmpty`]`.
     #[must_use]
     pub fn is_queue_empty(&self) -> bool {
         matches!(self, Self::SubmissionQueueEmpty)
     }
 
-    /// Panics if the MaintainResult is not Ok.
+    /// Returns true if the maintain operation did not time out.
+    ///
+    /// This is the case for both [`Self::SubmissionQueueEmpty`] and [`Self::Ok`].
+    #[must_use]
+    pub fn is_ok(&self) -> bool {
+        !self.is_timeout()
+    }
+
+    /// Returns true if the result is [`Self::Timeout`].
+    #[must_use]
+    pub fn is_timeout(&self) -> bool {
+        matches!(self, Self::Timeout)
+    }
+
+    /// Panics if the MaintainResult is [`Self::Timeout`].
+    #[track_caller]
     pub fn panic_on_timeout(self) {
-        let _ = self;
+        if self.is_timeout() {
+            panic!("Device::poll timed out");
+        }
     }
 }
 
+#[test]
+fn maintain_result_timeout() {
+    assert!(MaintainResult::Ok.is_ok());
+    assert!(MaintainResult::SubmissionQueueEmpty.is_ok());
+    assert!(MaintainResult::Timeout.is_timeout());
+    assert!(!MaintainResult::Timeout.is_ok());
+    MaintainResult::Ok.panic_on_timeout();
+    MaintainResult::SubmissionQueueEmpty.panic_on_timeout();
+
+    assert_eq!(
+        MaintainResult::from_wait(true, true),
+        MaintainResult::SubmissionQueueEmpty
+    );
+    assert_eq!(MaintainResult::from_wait(true, false), MaintainResult::Ok);
+    assert_eq!(
+        MaintainResult::from_wait(false, true),
+        MaintainResult::Timeout
+    );
+}
+
+#[test]
+#[should_panic = "timed out"]
+fn maintain_result_panic_on_timeout() {
+    // This is how wgpu-core reports a wait that timed out before the queue drained.
+    MaintainResult::from_wait(false, false).panic_on_timeout();
+}
+
 /// State of the stencil operation (fixed-pipeline stage).
 ///
 /// For use in [`DepthStencilState`].
@@ -4867,11 +8047,24 @@ pub struct StencilState {
 
 impl StencilState {
     /// Returns true if the stencil test is enabled.
+    ///
+    /// The stencil test is enabled if at least one face is not [`StencilFaceState::IGNORE`],
+    /// *and* at least one of the read and write masks is non-zero. A non-zero mask alone is
+    /// not enough, as ignored faces neither test nor modify the stencil buffer.
     #[must_use]
     pub fn is_enabled(&self) -> bool {
         (self.front != StencilFaceState::IGNORE || self.back != StencilFaceState::IGNORE)
             && (self.read_mask != 0 || self.write_mask != 0)
     }
+    /// Returns true if the stencil test has no effect, and can be dropped from the pipeline.
+    ///
+    /// This is the case if both faces are [`StencilFaceState::IGNORE`], or if both the
+    /// read and write masks are zero. It is always the inverse of [`Self::is_enabled`].
+    #[must_use]
+    pub fn is_effectively_disabled(&self) -> bool {
+        (self.front == StencilFaceState::IGNORE && self.back == StencilFaceState::IGNORE)
+            || (self.read_mask == 0 && self.write_mask == 0)
+    }
     /// Returns true if the state doesn't mutate the target values.
     #[must_use]
     pub fn is_read_only(&self, cull_mode: Option<Face>) -> bool {
@@ -4895,6 +8088,41 @@ impl StencilState {
     }
 }
 
+#[test]
+fn stencil_state_enabled() {
+    let face = StencilFaceState {
+        compare: CompareFunction::Equal,
+        ..StencilFaceState::IGNORE
+    };
+    let states = [
+        StencilState::default(),
+        StencilState {
+            read_mask: 0xff,
+            write_mask: 0xff,
+            ..Default::default()
+        },
+        StencilState {
+            front: face,
+            ..Default::default()
+        },
+        StencilState {
+            front: face,
+            read_mask: 0xff,
+            ..Default::default()
+        },
+        StencilState {
+            back: face,
+            write_mask: 0xff,
+            ..Default::default()
+        },
+    ];
+    let enabled: Vec<_> = states.iter().map(StencilState::is_enabled).collect();
+    assert_eq!(enabled, [false, false, false, true, true]);
+    for state in &states {
+        assert_eq!(state.is_effectively_disabled(), !state.is_enabled());
+    }
+}
+
 /// Describes the biasing setting for the depth target.
 ///
 /// For use in [`DepthStencilState`].
@@ -4914,6 +8142,21 @@ pub struct DepthBiasState {
 }
 
 impl DepthBiasState {
+    /// No depth biasing, the same as [`DepthBiasState::default`].
+    ///
+    /// All fields are positive zero, so this compares and hashes equal to the default.
+    pub const NONE: Self = Self::new(0, 0.0, 0.0);
+
+    /// Creates a depth bias state from its constant factor, slope factor and clamp value.
+    #[must_use]
+    pub const fn new(constant: i32, slope_scale: f32, clamp: f32) -> Self {
+        Self {
+            constant,
+            slope_scale,
+            clamp,
+        }
+    }
+
     /// Returns true if the depth biasing is enabled.
     #[must_use]
     pub fn is_enabled(&self) -> bool {
@@ -4939,6 +8182,24 @@ impl PartialEq for DepthBiasState {
 
 impl Eq for DepthBiasState {}
 
+#[test]
+fn depth_bias_state_none() {
+    assert_eq!(DepthBiasState::NONE, DepthBiasState::default());
+    assert!(!DepthBiasState::NONE.is_enabled());
+    assert_ne!(DepthBiasState::NONE, DepthBiasState::new(0, -0.0, 0.0));
+
+    let bias = DepthBiasState::new(2, 2.0, 0.0);
+    assert!(bias.is_enabled());
+    assert_eq!(
+        bias,
+        DepthBiasState {
+            constant: 2,
+            slope_scale: 2.0,
+            clamp: 0.0,
+        }
+    );
+}
+
 /// Operation to perform to the output attachment at the start of a render pass.
 ///
 /// Corresponds to [WebGPU `GPULoadOp`](https://gpuweb.github.io/gpuweb/#enumdef-gpuloadop),
@@ -5054,6 +8315,21 @@ pub struct DepthStencilState {
 }
 
 impl DepthStencilState {
+    /// Creates a state that neither tests nor writes depth and stencil values.
+    ///
+    /// This is useful to bind a depth/stencil attachment of the given `format` read-only,
+    /// for example to reuse the depth buffer of a depth prepass.
+    #[must_use]
+    pub fn read_only(format: TextureFormat) -> Self {
+        Self {
+            format,
+            depth_write_enabled: false,
+            depth_compare: CompareFunction::Always,
+            stencil: StencilState::default(),
+            bias: DepthBiasState::default(),
+        }
+    }
+
     /// Returns true if the depth testing is enabled.
     #[must_use]
     pub fn is_depth_enabled(&self) -> bool {
@@ -5079,6 +8355,16 @@ impl DepthStencilState {
     }
 }
 
+#[test]
+fn depth_stencil_state_read_only() {
+    let state = DepthStencilState::read_only(TextureFormat::Depth24PlusStencil8);
+    assert!(!state.is_depth_enabled());
+    assert!(!state.stencil.is_enabled());
+    for cull_mode in [None, Some(Face::Front), Some(Face::Back)] {
+        assert!(state.is_read_only(cull_mode));
+    }
+}
+
 /// Format of indices used with pipeline.
 ///
 /// Corresponds to [WebGPU `GPUIndexFormat`](
@@ -5103,6 +8389,27 @@ impl IndexFormat {
             IndexFormat::Uint32 => 4,
         }
     }
+
+    /// Returns the largest index value which can be stored in the index format.
+    ///
+    /// For strip topologies this equals [`Self::primitive_restart_value`], so the largest
+    /// index which can refer to a vertex is one less.
+    #[must_use]
+    pub fn max_index(&self) -> u32 {
+        match self {
+            IndexFormat::Uint16 => u16::MAX as u32,
+            IndexFormat::Uint32 => u32::MAX,
+        }
+    }
+
+    /// Returns the index value which restarts the primitive when using a strip topology,
+    /// see [`PrimitiveState::strip_index_format`].
+    ///
+    /// This is `0xFFFF` for [`Self::Uint16`] and `0xFFFFFFFF` for [`Self::Uint32`].
+    #[must_use]
+    pub fn primitive_restart_value(&self) -> u32 {
+        self.max_index()
+    }
 }
 
 /// Operation to perform on the stencil value.
@@ -5136,6 +8443,43 @@ pub enum StencilOperation {
     DecrementWrap = 7,
 }
 
+impl StencilOperation {
+    /// Returns true if the operation may change the stencil value, that is, if it is not
+    /// [`StencilOperation::Keep`].
+    #[must_use]
+    pub fn mutates(&self) -> bool {
+        *self != Self::Keep
+    }
+
+    /// Returns true if the operation uses the reference value, that is, if it is
+    /// [`StencilOperation::Replace`].
+    #[must_use]
+    pub fn needs_ref(&self) -> bool {
+        *self == Self::Replace
+    }
+}
+
+#[test]
+fn stencil_operation_classification() {
+    use StencilOperation as Op;
+
+    for op in [
+        Op::Zero,
+        Op::Invert,
+        Op::IncrementClamp,
+        Op::DecrementClamp,
+        Op::IncrementWrap,
+        Op::DecrementWrap,
+    ] {
+        assert!(op.mutates());
+        assert!(!op.needs_ref());
+    }
+    assert!(!Op::Keep.mutates());
+    assert!(!Op::Keep.needs_ref());
+    assert!(Op::Replace.mutates());
+    assert!(Op::Replace.needs_ref());
+}
+
 /// Describes stencil state in a render pipeline.
 ///
 /// If you are not using stencil state, set this to [`StencilFaceState::IGNORE`].
@@ -5166,21 +8510,78 @@ impl StencilFaceState {
         pass_op: StencilOperation::Keep,
     };
 
+    /// Writes the reference value to the stencil buffer where the stencil and depth tests pass,
+    /// keeping it everywhere else.
+    ///
+    /// This is commonly used to mark the pixels covered by an object as a mask, for example for
+    /// outlines or portals.
+    #[must_use]
+    pub const fn write_on_pass(compare: CompareFunction) -> Self {
+        Self::IGNORE
+            .with_compare(compare)
+            .with_pass_op(StencilOperation::Replace)
+    }
+
+    /// Writes the reference value everywhere the depth test passes.
+    ///
+    /// This is the same as [`Self::write_on_pass`] with [`CompareFunction::Always`].
+    #[must_use]
+    pub const fn always_replace() -> Self {
+        Self::write_on_pass(CompareFunction::Always)
+    }
+
+    /// Increments the stencil buffer, clamping to the maximum value, where the stencil and depth
+    /// tests pass, keeping it everywhere else.
+    ///
+    /// This is commonly used to count overlapping objects, for example nested portals.
+    #[must_use]
+    pub const fn increment_on_pass(compare: CompareFunction) -> Self {
+        Self::IGNORE
+            .with_compare(compare)
+            .with_pass_op(StencilOperation::IncrementClamp)
+    }
+
+    /// Returns this state with [`Self::compare`] set to `compare`.
+    #[must_use]
+    pub const fn with_compare(mut self, compare: CompareFunction) -> Self {
+        self.compare = compare;
+        self
+    }
+
+    /// Returns this state with [`Self::fail_op`] set to `fail_op`.
+    #[must_use]
+    pub const fn with_fail_op(mut self, fail_op: StencilOperation) -> Self {
+        self.fail_op = fail_op;
+        self
+    }
+
+    /// Returns this state with [`Self::depth_fail_op`] set to `depth_fail_op`.
+    #[must_use]
+    pub const fn with_depth_fail_op(mut self, depth_fail_op: StencilOperation) -> Self {
+        self.depth_fail_op = depth_fail_op;
+        self
+    }
+
+    /// Returns this state with [`Self::pass_op`] set to `pass_op`.
+    #[must_use]
+    pub const fn with_pass_op(mut self, pass_op: StencilOperation) -> Self {
+        self.pass_op = pass_op;
+        self
+    }
+
     /// Returns true if the face state uses the reference value for testing or operation.
     #[must_use]
     pub fn needs_ref_value(&self) -> bool {
         self.compare.needs_ref_value()
-            || self.fail_op == StencilOperation::Replace
-            || self.depth_fail_op == StencilOperation::Replace
-            || self.pass_op == StencilOperation::Replace
+            || self.fail_op.needs_ref()
+            || self.depth_fail_op.needs_ref()
+            || self.pass_op.needs_ref()
     }
 
     /// Returns true if the face state doesn't mutate the target values.
     #[must_use]
     pub fn is_read_only(&self) -> bool {
-        self.pass_op == StencilOperation::Keep
-            && self.depth_fail_op == StencilOperation::Keep
-            && self.fail_op == StencilOperation::Keep
+        !self.pass_op.mutates() && !self.depth_fail_op.mutates() && !self.fail_op.mutates()
     }
 }
 
@@ -5190,6 +8591,43 @@ impl Default for StencilFaceState {
     }
 }
 
+#[test]
+fn stencil_face_state_presets() {
+    let write = StencilFaceState::write_on_pass(CompareFunction::Always);
+    assert_eq!(write, StencilFaceState::always_replace());
+    assert_eq!(
+        write,
+        StencilFaceState {
+            compare: CompareFunction::Always,
+            fail_op: StencilOperation::Keep,
+            depth_fail_op: StencilOperation::Keep,
+            pass_op: StencilOperation::Replace,
+        }
+    );
+    assert!(write.needs_ref_value());
+    assert!(!write.is_read_only());
+
+    let increment = StencilFaceState::increment_on_pass(CompareFunction::Always);
+    assert_eq!(increment.pass_op, StencilOperation::IncrementClamp);
+    assert!(!increment.needs_ref_value());
+    assert!(StencilFaceState::increment_on_pass(CompareFunction::Equal).needs_ref_value());
+
+    let custom = StencilFaceState::default()
+        .with_compare(CompareFunction::NotEqual)
+        .with_fail_op(StencilOperation::Zero)
+        .with_depth_fail_op(StencilOperation::Invert)
+        .with_pass_op(StencilOperation::DecrementWrap);
+    assert_eq!(
+        custom,
+        StencilFaceState {
+            compare: CompareFunction::NotEqual,
+            fail_op: StencilOperation::Zero,
+            depth_fail_op: StencilOperation::Invert,
+            pass_op: StencilOperation::DecrementWrap,
+        }
+    );
+}
+
 /// Comparison function used for depth and stencil operations.
 ///
 /// Corresponds to [WebGPU `GPUCompareFunction`](
@@ -5224,14 +8662,58 @@ pub enum CompareFunction {
 impl CompareFunction {
     /// Returns true if the comparison depends on the reference value.
     #[must_use]
-    pub fn needs_ref_value(self) -> bool {
+    pub fn needs_ref_value(self) -> bool {
+        match self {
+            Self::Never | Self::Always => false,
+            _ => true,
+        }
+    }
+
+    /// Returns the comparison with its direction reversed, as needed when switching between
+    /// a standard and a reversed-Z depth buffer.
+    ///
+    /// [`Less`](Self::Less) and [`Greater`](Self::Greater) are swapped, as are
+    /// [`LessEqual`](Self::LessEqual) and [`GreaterEqual`](Self::GreaterEqual). The
+    /// non-directional comparisons are returned unchanged.
+    ///
+    /// Note that this is not the logical negation of the comparison: the inverse of
+    /// [`Less`](Self::Less) is [`Greater`](Self::Greater), not
+    /// [`GreaterEqual`](Self::GreaterEqual).
+    #[must_use]
+    pub fn inverse(self) -> Self {
         match self {
-            Self::Never | Self::Always => false,
-            _ => true,
+            Self::Less => Self::Greater,
+            Self::Greater => Self::Less,
+            Self::LessEqual => Self::GreaterEqual,
+            Self::GreaterEqual => Self::LessEqual,
+            Self::Never | Self::Equal | Self::NotEqual | Self::Always => self,
         }
     }
 }
 
+#[test]
+fn compare_function_inverse() {
+    let functions = [
+        CompareFunction::Never,
+        CompareFunction::Less,
+        CompareFunction::Equal,
+        CompareFunction::LessEqual,
+        CompareFunction::Greater,
+        CompareFunction::NotEqual,
+        CompareFunction::GreaterEqual,
+        CompareFunction::Always,
+    ];
+    for function in functions {
+        assert_eq!(function.inverse().inverse(), function);
+    }
+    assert_eq!(CompareFunction::Less.inverse(), CompareFunction::Greater);
+    assert_eq!(
+        CompareFunction::GreaterEqual.inverse(),
+        CompareFunction::LessEqual
+    );
+    assert_eq!(CompareFunction::Equal.inverse(), CompareFunction::Equal);
+}
+
 /// Whether a vertex buffer is indexed by vertex or by instance.
 ///
 /// Consider a call to [`RenderPass::draw`] like this:
@@ -5323,6 +8805,234 @@ pub struct VertexAttribute {
     pub shader_location: ShaderLocation,
 }
 
+impl VertexAttribute {
+    /// Returns the offset of the first byte after this attribute, that is `offset + format.size()`.
+    ///
+    /// This saturates at [`u64::MAX`] if the offset is so large that the end overflows.
+    #[must_use]
+    pub const fn end_offset(&self) -> BufferAddress {
+        self.offset.saturating_add(self.format.size())
+    }
+}
+
+/// Error returned by [`validate_attributes`], naming the location of the offending attribute.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum VertexLayoutError {
+    /// The attribute ends past the array stride.
+    AttributeOutOfBounds {
+        /// Location of the attribute.
+        location: ShaderLocation,
+        /// [`VertexAttribute::end_offset`] of the attribute.
+        end_offset: BufferAddress,
+        /// Array stride of the vertex buffer.
+        stride: BufferAddress,
+    },
+    /// The offset of the attribute is not a multiple of the required alignment.
+    UnalignedOffset {
+        /// Location of the attribute.
+        location: ShaderLocation,
+        /// Offset of the attribute.
+        offset: BufferAddress,
+        /// Required alignment of the offset.
+        alignment: BufferAddress,
+    },
+    /// More than one attribute uses the location.
+    DuplicateLocation(ShaderLocation),
+}
+
+impl core::fmt::Display for VertexLayoutError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match *self {
+            Self::AttributeOutOfBounds {
+                location,
+                end_offset,
+                stride,
+            } => write!(
+                f,
+                "vertex attribute at location {location} ends at offset {end_offset}, \
+                 past the array stride of {stride}"
+            ),
+            Self::UnalignedOffset {
+                location,
+                offset,
+                alignment,
+            } => write!(
+                f,
+                "vertex attribute at location {location} has offset {offset}, \
+                 which is not a multiple of {alignment}"
+            ),
+            Self::DuplicateLocation(location) => {
+                write!(f, "multiple vertex attributes use location {location}")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for VertexLayoutError {}
+
+/// Checks that `attributes` form a valid layout for a vertex buffer with the given
+/// `array_stride`.
+///
+/// Every attribute must end within the stride, its offset must be a multiple of
+/// `min(4, format.size())`, as required by WebGPU, and no two attributes may share a
+/// shader location. A stride of 0 is not bounded by this check, such attributes are
+/// instead limited by [`Limits::max_vertex_buffer_array_stride`], but they are still out of
+/// bounds if their end overflows a `u64`.
+pub fn validate_attributes(
+    attributes: &[VertexAttribute],
+    array_stride: BufferAddress,
+) -> Result<(), VertexLayoutError> {
+    for (i, attribute) in attributes.iter().enumerate() {
+        let location = attribute.shader_location;
+        let in_bounds = match attribute.offset.checked_add(attribute.format.size()) {
+            Some(end_offset) => array_stride == 0 || end_offset <= array_stride,
+            // An attribute ending past `u64::MAX` can't fit in any buffer.
+            None => false,
+        };
+        if !in_bounds {
+            return Err(VertexLayoutError::AttributeOutOfBounds {
+                location,
+                end_offset: attribute.end_offset(),
+                stride: array_stride,
+            });
+        }
+        let alignment = attribute.format.size().min(4);
+        if attribute.offset % alignment != 0 {
+            return Err(VertexLayoutError::UnalignedOffset {
+                location,
+                offset: attribute.offset,
+                alignment,
+            });
+        }
+        if attributes[..i]
+            .iter()
+            .any(|other| other.shader_location == location)
+        {
+            return Err(VertexLayoutError::DuplicateLocation(location));
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn vertex_validate_attributes() {
+    let attr = |format, offset, shader_location| VertexAttribute {
+        format,
+        offset,
+        shader_location,
+    };
+    let attributes = [
+        attr(VertexFormat::Float32x3, 0, 0),
+        attr(VertexFormat::Unorm8x4, 12, 1),
+        attr(VertexFormat::Uint16x2, 16, 2),
+    ];
+    assert_eq!(attributes[2].end_offset(), 20);
+    assert_eq!(validate_attributes(&attributes, 20), Ok(()));
+    assert_eq!(validate_attributes(&attributes, 0), Ok(()));
+    assert_eq!(
+        validate_attributes(&attributes, 16),
+        Err(VertexLayoutError::AttributeOutOfBounds {
+            location: 2,
+            end_offset: 20,
+            stride: 16
+        })
+    );
+    assert_eq!(
+        validate_attributes(&[attr(VertexFormat::Uint16x2, 2, 0)], 8),
+        Err(VertexLayoutError::UnalignedOffset {
+            location: 0,
+            offset: 2,
+            alignment: 4
+        })
+    );
+    // 64-bit formats only need 4 byte alignment.
+    assert_eq!(
+        validate_attributes(&[attr(VertexFormat::Float64, 4, 0)], 12),
+        Ok(())
+    );
+    assert_eq!(
+        validate_attributes(
+            &[
+                attr(VertexFormat::Float32, 0, 3),
+                attr(VertexFormat::Float32, 4, 3)
+            ],
+            8
+        ),
+        Err(VertexLayoutError::DuplicateLocation(3))
+    );
+
+    let overflowing = attr(VertexFormat::Float32, u64::MAX, 0);
+    assert_eq!(overflowing.end_offset(), u64::MAX);
+    for stride in [0, 16] {
+        assert_eq!(
+            validate_attributes(&[overflowing], stride),
+            Err(VertexLayoutError::AttributeOutOfBounds {
+                location: 0,
+                end_offset: u64::MAX,
+                stride
+            })
+        );
+    }
+}
+
+/// Computes the minimal `array_stride` of a vertex buffer containing `attributes`.
+///
+/// The stride is the largest `offset + format.size()` of any attribute, rounded up to
+/// [`VERTEX_STRIDE_ALIGNMENT`]. Attributes may be in any order and may overlap.
+/// Returns 0 if `attributes` is empty.
+#[must_use]
+pub fn compute_vertex_stride(attributes: &[VertexAttribute]) -> BufferAddress {
+    let end = attributes
+        .iter()
+        .map(VertexAttribute::end_offset)
+        .max()
+        .unwrap_or(0);
+    math::align_up(end, VERTEX_STRIDE_ALIGNMENT)
+}
+
+#[test]
+fn vertex_stride() {
+    let attr = |format, offset| VertexAttribute {
+        format,
+        offset,
+        shader_location: 0,
+    };
+
+    assert_eq!(compute_vertex_stride(&[]), 0);
+    assert_eq!(
+        compute_vertex_stride(&[
+            attr(VertexFormat::Float32x3, 0),
+            attr(VertexFormat::Float32x2, 12)
+        ]),
+        20
+    );
+    // Out of order attributes.
+    assert_eq!(
+        compute_vertex_stride(&[
+            attr(VertexFormat::Float32x4, 16),
+            attr(VertexFormat::Float32x4, 0)
+        ]),
+        32
+    );
+    // Overlapping attributes, the end of the last one determines the stride.
+    assert_eq!(
+        compute_vertex_stride(&[
+            attr(VertexFormat::Float32x4, 0),
+            attr(VertexFormat::Float32, 4)
+        ]),
+        16
+    );
+    // Rounded up to the stride alignment.
+    assert_eq!(
+        compute_vertex_stride(&[
+            attr(VertexFormat::Float32x2, 0),
+            attr(VertexFormat::Unorm8x2, 8)
+        ]),
+        12
+    );
+}
+
 /// Vertex Format for a [`VertexAttribute`] (input).
 ///
 /// Corresponds to [WebGPU `GPUVertexFormat`](
@@ -5470,6 +9180,282 @@ impl VertexFormat {
             Self::Float64x4 => 32,
         }
     }
+
+    /// Returns the natural alignment of the format's components in bytes.
+    ///
+    /// This is the size of the largest scalar making up the format, with packed formats
+    /// like [`Self::Unorm10_10_10_2`] aligned as the 32-bit integer they are packed into.
+    /// Note that WebGPU only requires [`VertexAttribute::offset`] to be a multiple of
+    /// `min(4, size)`, so this is stricter than necessary for the 64-bit formats.
+    #[must_use]
+    pub const fn alignment(&self) -> u64 {
+        match self {
+            Self::Uint8
+            | Self::Uint8x2
+            | Self::Uint8x4
+            | Self::Sint8
+            | Self::Sint8x2
+            | Self::Sint8x4
+            | Self::Unorm8
+            | Self::Unorm8x2
+            | Self::Unorm8x4
+            | Self::Snorm8
+            | Self::Snorm8x2
+            | Self::Snorm8x4 => 1,
+            Self::Uint16
+            | Self::Uint16x2
+            | Self::Uint16x4
+            | Self::Sint16
+            | Self::Sint16x2
+            | Self::Sint16x4
+            | Self::Unorm16
+            | Self::Unorm16x2
+            | Self::Unorm16x4
+            | Self::Snorm16
+            | Self::Snorm16x2
+            | Self::Snorm16x4
+            | Self::Float16
+            | Self::Float16x2
+            | Self::Float16x4 => 2,
+            Self::Float32
+            | Self::Float32x2
+            | Self::Float32x3
+            | Self::Float32x4
+            | Self::Uint32
+            | Self::Uint32x2
+            | Self::Uint32x3
+            | Self::Uint32x4
+            | Self::Sint32
+            | Self::Sint32x2
+            | Self::Sint32x3
+            | Self::Sint32x4
+            | Self::Unorm10_10_10_2
+            | Self::Unorm8x4Bgra => 4,
+            Self::Float64 | Self::Float64x2 | Self::Float64x3 | Self::Float64x4 => 8,
+        }
+    }
+
+    /// Returns the number of components the format has, as seen by the shader.
+    ///
+    /// Packed formats like [`Self::Unorm10_10_10_2`] count each packed channel as a component.
+    #[must_use]
+    pub const fn components(&self) -> u32 {
+        match self {
+            Self::Uint8
+            | Self::Sint8
+            | Self::Unorm8
+            | Self::Snorm8
+            | Self::Uint16
+            | Self::Sint16
+            | Self::Unorm16
+            | Self::Snorm16
+            | Self::Float16
+            | Self::Float32
+            | Self::Uint32
+            | Self::Sint32
+            | Self::Float64 => 1,
+            Self::Uint8x2
+            | Self::Sint8x2
+            | Self::Unorm8x2
+            | Self::Snorm8x2
+            | Self::Uint16x2
+            | Self::Sint16x2
+            | Self::Unorm16x2
+            | Self::Snorm16x2
+            | Self::Float16x2
+            | Self::Float32x2
+            | Self::Uint32x2
+            | Self::Sint32x2
+            | Self::Float64x2 => 2,
+            Self::Float32x3 | Self::Uint32x3 | Self::Sint32x3 | Self::Float64x3 => 3,
+            Self::Uint8x4
+            | Self::Sint8x4
+            | Self::Unorm8x4
+            | Self::Snorm8x4
+            | Self::Uint16x4
+            | Self::Sint16x4
+            | Self::Unorm16x4
+            | Self::Snorm16x4
+            | Self::Float16x4
+            | Self::Float32x4
+            | Self::Uint32x4
+            | Self::Sint32x4
+            | Self::Float64x4
+            | Self::Unorm10_10_10_2
+            | Self::Unorm8x4Bgra => 4,
+        }
+    }
+
+    /// Returns how the components of the format are stored and interpreted.
+    #[must_use]
+    pub const fn scalar_kind(&self) -> VertexScalarKind {
+        match self {
+            Self::Uint8
+            | Self::Uint8x2
+            | Self::Uint8x4
+            | Self::Uint16
+            | Self::Uint16x2
+            | Self::Uint16x4
+            | Self::Uint32
+            | Self::Uint32x2
+            | Self::Uint32x3
+            | Self::Uint32x4 => VertexScalarKind::Uint,
+            Self::Sint8
+            | Self::Sint8x2
+            | Self::Sint8x4
+            | Self::Sint16
+            | Self::Sint16x2
+            | Self::Sint16x4
+            | Self::Sint32
+            | Self::Sint32x2
+            | Self::Sint32x3
+            | Self::Sint32x4 => VertexScalarKind::Sint,
+            Self::Unorm8
+            | Self::Unorm8x2
+            | Self::Unorm8x4
+            | Self::Unorm16
+            | Self::Unorm16x2
+            | Self::Unorm16x4
+            | Self::Unorm10_10_10_2
+            | Self::Unorm8x4Bgra => VertexScalarKind::Unorm,
+            Self::Snorm8
+            | Self::Snorm8x2
+            | Self::Snorm8x4
+            | Self::Snorm16
+            | Self::Snorm16x2
+            | Self::Snorm16x4 => VertexScalarKind::Snorm,
+            Self::Float16
+            | Self::Float16x2
+            | Self::Float16x4
+            | Self::Float32
+            | Self::Float32x2
+            | Self::Float32x3
+            | Self::Float32x4
+            | Self::Float64
+            | Self::Float64x2
+            | Self::Float64x3
+            | Self::Float64x4 => VertexScalarKind::Float,
+        }
+    }
+
+    /// Returns true if the format holds integers which are normalized to floats in shaders.
+    ///
+    /// This is the case for the `Unorm` and `Snorm` formats.
+    #[must_use]
+    pub const fn is_normalized(&self) -> bool {
+        self.scalar_kind().is_normalized()
+    }
+
+    /// Returns true if the format holds integers which are read as integers in shaders.
+    ///
+    /// This is the case for the `Uint` and `Sint` formats.
+    #[must_use]
+    pub const fn is_integer(&self) -> bool {
+        matches!(
+            self.scalar_kind(),
+            VertexScalarKind::Uint | VertexScalarKind::Sint
+        )
+    }
+
+    /// Returns true if the format holds floating point values.
+    #[must_use]
+    pub const fn is_float(&self) -> bool {
+        matches!(self.scalar_kind(), VertexScalarKind::Float)
+    }
+}
+
+#[test]
+fn vertex_format_classification() {
+    let formats = [
+        VertexFormat::Uint8,
+        VertexFormat::Uint8x2,
+        VertexFormat::Uint8x4,
+        VertexFormat::Sint8,
+        VertexFormat::Sint8x2,
+        VertexFormat::Sint8x4,
+        VertexFormat::Unorm8,
+        VertexFormat::Unorm8x2,
+        VertexFormat::Unorm8x4,
+        VertexFormat::Snorm8,
+        VertexFormat::Snorm8x2,
+        VertexFormat::Snorm8x4,
+        VertexFormat::Uint16,
+        VertexFormat::Uint16x2,
+        VertexFormat::Uint16x4,
+        VertexFormat::Sint16,
+        VertexFormat::Sint16x2,
+        VertexFormat::Sint16x4,
+        VertexFormat::Unorm16,
+        VertexFormat::Unorm16x2,
+        VertexFormat::Unorm16x4,
+        VertexFormat::Snorm16,
+        VertexFormat::Snorm16x2,
+        VertexFormat::Snorm16x4,
+        VertexFormat::Float16,
+        VertexFormat::Float16x2,
+        VertexFormat::Float16x4,
+        VertexFormat::Float32,
+        VertexFormat::Float32x2,
+        VertexFormat::Float32x3,
+        VertexFormat::Float32x4,
+        VertexFormat::Uint32,
+        VertexFormat::Uint32x2,
+        VertexFormat::Uint32x3,
+        VertexFormat::Uint32x4,
+        VertexFormat::Sint32,
+        VertexFormat::Sint32x2,
+        VertexFormat::Sint32x3,
+        VertexFormat::Sint32x4,
+        VertexFormat::Float64,
+        VertexFormat::Float64x2,
+        VertexFormat::Float64x3,
+        VertexFormat::Float64x4,
+        VertexFormat::Unorm10_10_10_2,
+        VertexFormat::Unorm8x4Bgra,
+    ];
+    for format in formats {
+        let classes = [
+            format.is_normalized(),
+            format.is_integer(),
+            format.is_float(),
+        ];
+        assert_eq!(
+            classes.iter().filter(|&&class| class).count(),
+            1,
+            "{format:?}"
+        );
+    }
+    assert!(VertexFormat::Unorm10_10_10_2.is_normalized());
+    assert!(VertexFormat::Sint16x2.is_integer());
+    assert!(VertexFormat::Float16.is_float());
+}
+
+/// How the components of a [`VertexFormat`] are stored in the vertex buffer.
+///
+/// [`Self::Unorm`], [`Self::Snorm`] and [`Self::Float`] are all read as floats in shaders,
+/// while [`Self::Uint`] and [`Self::Sint`] are read as integers.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum VertexScalarKind {
+    /// Unsigned integer. `u32` in shaders.
+    Uint,
+    /// Signed integer. `i32` in shaders.
+    Sint,
+    /// Unsigned integer normalized to float [0, 1]. `f32` in shaders.
+    Unorm,
+    /// Signed integer normalized to float [-1, 1]. `f32` in shaders.
+    Snorm,
+    /// Floating point, of half, single or double precision. `f32` in shaders.
+    Float,
+}
+
+impl VertexScalarKind {
+    /// Returns true if the components are integers normalized to a float range.
+    #[must_use]
+    pub const fn is_normalized(&self) -> bool {
+        matches!(self, Self::Unorm | Self::Snorm)
+    }
 }
 
 bitflags::bitflags! {
@@ -5516,12 +9502,134 @@ bitflags::bitflags! {
         /// Allow a buffer to be the destination buffer for a [`CommandEncoder::resolve_query_set`] operation.
         const QUERY_RESOLVE = 1 << 9;
         /// Allows a buffer to be used as input for a bottom level acceleration structure build
+        ///
+        /// Requires [`Features::EXPERIMENTAL_RAY_TRACING_ACCELERATION_STRUCTURE`].
         const BLAS_INPUT = 1 << 10;
         /// Allows a buffer to be used as input for a top level acceleration structure build
+        ///
+        /// Requires [`Features::EXPERIMENTAL_RAY_TRACING_ACCELERATION_STRUCTURE`].
         const TLAS_INPUT = 1 << 11;
     }
 }
 
+impl BufferUsages {
+    /// Returns the features required to use all of the usages.
+    ///
+    /// | Usage                                  | Required feature                                              |
+    /// |----------------------------------------|---------------------------------------------------------------|
+    /// | [`BLAS_INPUT`](Self::BLAS_INPUT)       | [`Features::EXPERIMENTAL_RAY_TRACING_ACCELERATION_STRUCTURE`] |
+    /// | [`TLAS_INPUT`](Self::TLAS_INPUT)       | [`Features::EXPERIMENTAL_RAY_TRACING_ACCELERATION_STRUCTURE`] |
+    ///
+    /// All other usages don't require any features.
+    #[must_use]
+    pub fn requires_features(&self) -> Features {
+        let mut features = Features::empty();
+        if self.intersects(Self::BLAS_INPUT | Self::TLAS_INPUT) {
+            features |= Features::EXPERIMENTAL_RAY_TRACING_ACCELERATION_STRUCTURE;
+        }
+        features
+    }
+
+    /// Returns true if any of the usages requires a feature which is not in `features`.
+    #[must_use]
+    pub fn contains_unsupported(&self, features: Features) -> bool {
+        !features.contains(self.requires_features())
+    }
+
+    /// Returns true if the buffer can be mapped, that is, if it has [`Self::MAP_READ`]
+    /// or [`Self::MAP_WRITE`].
+    #[must_use]
+    pub const fn is_mappable(&self) -> bool {
+        self.intersects(Self::MAP_READ.union(Self::MAP_WRITE))
+    }
+
+    /// Returns true if the only usages are [`Self::COPY_SRC`] and [`Self::COPY_DST`], that is,
+    /// if the buffer is purely used for transfers.
+    #[must_use]
+    pub const fn is_copy_only(&self) -> bool {
+        Self::COPY_SRC.union(Self::COPY_DST).contains(*self)
+    }
+
+    /// Returns true if none of the usages allow the GPU to write to the buffer, that is, it has
+    /// none of [`Self::STORAGE`], [`Self::COPY_DST`] and [`Self::QUERY_RESOLVE`].
+    #[must_use]
+    pub const fn is_gpu_readonly(&self) -> bool {
+        !self.intersects(
+            Self::STORAGE
+                .union(Self::COPY_DST)
+                .union(Self::QUERY_RESOLVE),
+        )
+    }
+
+    /// Checks that the mapping usages are only combined with other usages that are allowed.
+    ///
+    /// Unless [`Features::MAPPABLE_PRIMARY_BUFFERS`] is enabled, [`Self::MAP_READ`] may only
+    /// be combined with [`Self::COPY_DST`], and [`Self::MAP_WRITE`] may only be combined with
+    /// [`Self::COPY_SRC`].
+    pub fn validate_mapping(&self, features: Features) -> Result<(), BufferUsageError> {
+        if features.contains(Features::MAPPABLE_PRIMARY_BUFFERS) {
+            return Ok(());
+        }
+        let write_mismatch =
+            self.contains(Self::MAP_WRITE) && !(Self::MAP_WRITE | Self::COPY_SRC).contains(*self);
+        let read_mismatch =
+            self.contains(Self::MAP_READ) && !(Self::MAP_READ | Self::COPY_DST).contains(*self);
+        if write_mismatch || read_mismatch {
+            return Err(BufferUsageError { usage: *self });
+        }
+        Ok(())
+    }
+}
+
+/// Error returned by [`BufferUsages::validate_mapping`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct BufferUsageError {
+    /// The buffer usages which are not allowed together.
+    pub usage: BufferUsages,
+}
+
+impl core::fmt::Display for BufferUsageError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "Buffer usages {:?} are not allowed together without Features::MAPPABLE_PRIMARY_BUFFERS",
+            self.usage
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BufferUsageError {}
+
+#[test]
+fn buffer_usages_validate_mapping() {
+    let read = BufferUsages::MAP_READ | BufferUsages::COPY_DST;
+    let write = BufferUsages::MAP_WRITE | BufferUsages::COPY_SRC;
+    let read_vertex = BufferUsages::MAP_READ | BufferUsages::VERTEX;
+    let write_read = BufferUsages::MAP_WRITE | BufferUsages::MAP_READ;
+
+    assert!(read.is_mappable());
+    assert!(write.is_mappable());
+    assert!(!BufferUsages::COPY_DST.is_mappable());
+
+    let features = Features::empty();
+    assert_eq!(read.validate_mapping(features), Ok(()));
+    assert_eq!(write.validate_mapping(features), Ok(()));
+    assert_eq!(BufferUsages::VERTEX.validate_mapping(features), Ok(()));
+    assert_eq!(
+        read_vertex.validate_mapping(features),
+        Err(BufferUsageError { usage: read_vertex })
+    );
+    assert_eq!(
+        write_read.validate_mapping(features),
+        Err(BufferUsageError { usage: write_read })
+    );
+
+    let features = Features::MAPPABLE_PRIMARY_BUFFERS;
+    assert_eq!(read_vertex.validate_mapping(features), Ok(()));
+    assert_eq!(write_read.validate_mapping(features), Ok(()));
+}
+
 bitflags::bitflags! {
     /// Similar to `BufferUsages`, but used only for `CommandEncoder::transition_resources`.
     #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
@@ -5707,8 +9815,115 @@ pub enum PresentMode {
     ///
     /// Supported on DX12 on Windows 10, NVidia on Vulkan and Wayland on Vulkan.
     ///
-    /// This is traditionally called "Fast Vsync"
-    Mailbox = 5,
+    /// This is traditionally called "Fast Vsync"
+    Mailbox = 5,
+}
+
+impl PresentMode {
+    /// Returns the string name of the present mode.
+    #[must_use]
+    pub const fn to_str(self) -> &'static str {
+        match self {
+            Self::AutoVsync => "auto-vsync",
+            Self::AutoNoVsync => "auto-no-vsync",
+            Self::Fifo => "fifo",
+            Self::FifoRelaxed => "fifo-relaxed",
+            Self::Immediate => "immediate",
+            Self::Mailbox => "mailbox",
+        }
+    }
+
+    /// Returns true if the mode waits for vertical blanking to present frames that are on time.
+    ///
+    /// This is the case for [`Self::Fifo`], [`Self::FifoRelaxed`], [`Self::Mailbox`] and
+    /// [`Self::AutoVsync`]. Note that [`Self::FifoRelaxed`] can still tear when a frame
+    /// arrives late.
+    #[must_use]
+    pub const fn is_vsync(self) -> bool {
+        match self {
+            Self::AutoVsync | Self::Fifo | Self::FifoRelaxed | Self::Mailbox => true,
+            Self::AutoNoVsync | Self::Immediate => false,
+        }
+    }
+
+    /// Resolves this present mode against the modes in `supported`, usually
+    /// [`SurfaceCapabilities::present_modes`].
+    ///
+    /// [`AutoVsync`](Self::AutoVsync) resolves to the first of
+    /// [`FifoRelaxed`](Self::FifoRelaxed) and [`Fifo`](Self::Fifo) that is supported, and
+    /// [`AutoNoVsync`](Self::AutoNoVsync) to the first of [`Immediate`](Self::Immediate),
+    /// [`Mailbox`](Self::Mailbox) and [`Fifo`](Self::Fifo). Any other mode resolves to itself if
+    /// it is supported. Returns `None` if no supported mode could be chosen.
+    #[must_use]
+    pub fn resolve(self, supported: &[PresentMode]) -> Option<PresentMode> {
+        let candidates: &[Self] = match self {
+            Self::AutoVsync => &[Self::FifoRelaxed, Self::Fifo],
+            Self::AutoNoVsync => &[Self::Immediate, Self::Mailbox, Self::Fifo],
+            _ => &[self],
+        };
+        candidates
+            .iter()
+            .copied()
+            .find(|mode| supported.contains(mode))
+    }
+}
+
+impl core::fmt::Display for PresentMode {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.to_str())
+    }
+}
+
+/// Error returned when a string does not name a valid [`PresentMode`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ParsePresentModeError;
+
+impl core::fmt::Display for ParsePresentModeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("invalid present mode")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParsePresentModeError {}
+
+impl core::str::FromStr for PresentMode {
+    type Err = ParsePresentModeError;
+
+    /// Parses a present mode from the names returned by [`PresentMode::to_str`].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "auto-vsync" => Self::AutoVsync,
+            "auto-no-vsync" => Self::AutoNoVsync,
+            "fifo" => Self::Fifo,
+            "fifo-relaxed" => Self::FifoRelaxed,
+            "immediate" => Self::Immediate,
+            "mailbox" => Self::Mailbox,
+            _ => return Err(ParsePresentModeError),
+        })
+    }
+}
+
+#[test]
+fn present_mode_resolve() {
+    use PresentMode as M;
+
+    let fifo_only = [M::Fifo, M::FifoRelaxed];
+    assert_eq!(M::AutoVsync.resolve(&fifo_only), Some(M::FifoRelaxed));
+    assert_eq!(M::AutoNoVsync.resolve(&fifo_only), Some(M::Fifo));
+    assert_eq!(M::Mailbox.resolve(&fifo_only), None);
+    assert_eq!(M::Fifo.resolve(&fifo_only), Some(M::Fifo));
+
+    assert_eq!(M::AutoVsync.resolve(&[M::Fifo]), Some(M::Fifo));
+    assert_eq!(
+        M::AutoNoVsync.resolve(&[M::Fifo, M::Mailbox]),
+        Some(M::Mailbox)
+    );
+    assert_eq!(
+        M::AutoNoVsync.resolve(&[M::Mailbox, M::Immediate, M::Fifo]),
+        Some(M::Immediate)
+    );
+    assert_eq!(M::AutoVsync.resolve(&[]), None);
 }
 
 /// Specifies how the alpha channel of the textures should be handled during
@@ -5749,6 +9964,101 @@ impl Default for CompositeAlphaMode {
     }
 }
 
+impl CompositeAlphaMode {
+    /// Returns the name of the alpha mode, matching its serialized form.
+    #[must_use]
+    pub const fn to_str(self) -> &'static str {
+        match self {
+            Self::Auto => "auto",
+            Self::Opaque => "opaque",
+            Self::PreMultiplied => "premultiplied",
+            Self::PostMultiplied => "postmultiplied",
+            Self::Inherit => "inherit",
+        }
+    }
+
+    /// Resolves this alpha mode against the modes in `supported`, usually
+    /// [`SurfaceCapabilities::alpha_modes`].
+    ///
+    /// [`Auto`](Self::Auto) resolves to the first of [`Opaque`](Self::Opaque) and
+    /// [`Inherit`](Self::Inherit) that is supported. Any other mode resolves to itself if it is
+    /// supported. Returns `None` if no supported mode could be chosen.
+    #[must_use]
+    pub fn resolve(self, supported: &[CompositeAlphaMode]) -> Option<CompositeAlphaMode> {
+        let candidates: &[Self] = match self {
+            Self::Auto => &[Self::Opaque, Self::Inherit],
+            _ => &[self],
+        };
+        candidates
+            .iter()
+            .copied()
+            .find(|mode| supported.contains(mode))
+    }
+}
+
+impl core::fmt::Display for CompositeAlphaMode {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.to_str())
+    }
+}
+
+/// Error returned when a string does not name a valid [`CompositeAlphaMode`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ParseCompositeAlphaModeError;
+
+impl core::fmt::Display for ParseCompositeAlphaModeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("invalid composite alpha mode")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseCompositeAlphaModeError {}
+
+impl core::str::FromStr for CompositeAlphaMode {
+    type Err = ParseCompositeAlphaModeError;
+
+    /// Parses an alpha mode from the names returned by [`CompositeAlphaMode::to_str`].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "auto" => Self::Auto,
+            "opaque" => Self::Opaque,
+            "premultiplied" => Self::PreMultiplied,
+            "postmultiplied" => Self::PostMultiplied,
+            "inherit" => Self::Inherit,
+            _ => return Err(ParseCompositeAlphaModeError),
+        })
+    }
+}
+
+#[test]
+fn composite_alpha_mode_resolve() {
+    use CompositeAlphaMode as M;
+
+    for mode in [
+        M::Auto,
+        M::Opaque,
+        M::PreMultiplied,
+        M::PostMultiplied,
+        M::Inherit,
+    ] {
+        assert_eq!(mode.to_str().parse(), Ok(mode));
+    }
+    assert_eq!("Opaque".parse::<M>(), Err(ParseCompositeAlphaModeError));
+
+    assert_eq!(M::Auto.resolve(&[M::Inherit, M::Opaque]), Some(M::Opaque));
+    assert_eq!(
+        M::Auto.resolve(&[M::PreMultiplied, M::Inherit]),
+        Some(M::Inherit)
+    );
+    assert_eq!(M::Auto.resolve(&[M::PreMultiplied]), None);
+    assert_eq!(
+        M::PreMultiplied.resolve(&[M::Opaque, M::PreMultiplied]),
+        Some(M::PreMultiplied)
+    );
+    assert_eq!(M::PostMultiplied.resolve(&[M::Opaque]), None);
+}
+
 bitflags::bitflags! {
     /// Different ways that you can use a texture.
     ///
@@ -5790,6 +10100,85 @@ bitflags::bitflags! {
     }
 }
 
+impl TextureUsages {
+    /// Returns the features required to use all of the usages.
+    ///
+    /// | Usage                                    | Required feature              |
+    /// |------------------------------------------|-------------------------------|
+    /// | [`STORAGE_ATOMIC`](Self::STORAGE_ATOMIC) | [`Features::TEXTURE_ATOMIC`]  |
+    ///
+    /// All other usages don't require any features, though the usages supported by a
+    /// given format may depend on features, see [`TextureFormat::guaranteed_format_features`].
+    #[must_use]
+    pub fn requires_features(&self) -> Features {
+        let mut features = Features::empty();
+        if self.contains(Self::STORAGE_ATOMIC) {
+            features |= Features::TEXTURE_ATOMIC;
+        }
+        features
+    }
+
+    /// Returns true if any of the usages requires a feature which is not in `features`.
+    #[must_use]
+    pub fn contains_unsupported(&self, features: Features) -> bool {
+        !features.contains(self.requires_features())
+    }
+
+    /// Returns true if the only usages are [`Self::COPY_SRC`] and [`Self::COPY_DST`], that is,
+    /// if the texture is purely used for transfers.
+    #[must_use]
+    pub const fn is_copy_only(&self) -> bool {
+        Self::COPY_SRC.union(Self::COPY_DST).contains(*self)
+    }
+
+    /// Returns true if none of the usages allow the GPU to write to the texture, that is, it has
+    /// none of [`Self::STORAGE_BINDING`], [`Self::STORAGE_ATOMIC`], [`Self::COPY_DST`] and
+    /// [`Self::RENDER_ATTACHMENT`].
+    #[must_use]
+    pub const fn is_gpu_readonly(&self) -> bool {
+        !self.intersects(
+            Self::STORAGE_BINDING
+                .union(Self::STORAGE_ATOMIC)
+                .union(Self::COPY_DST)
+                .union(Self::RENDER_ATTACHMENT),
+        )
+    }
+}
+
+#[test]
+fn usages_copy_class() {
+    assert!(BufferUsages::COPY_SRC.is_copy_only());
+    assert!((BufferUsages::COPY_SRC | BufferUsages::COPY_DST).is_copy_only());
+    assert!(!(BufferUsages::COPY_DST | BufferUsages::MAP_READ).is_copy_only());
+    assert!((BufferUsages::VERTEX | BufferUsages::COPY_SRC).is_gpu_readonly());
+    assert!(!(BufferUsages::VERTEX | BufferUsages::COPY_DST).is_gpu_readonly());
+    assert!(!BufferUsages::QUERY_RESOLVE.is_gpu_readonly());
+
+    assert!(TextureUsages::COPY_DST.is_copy_only());
+    assert!(!(TextureUsages::COPY_DST | TextureUsages::TEXTURE_BINDING).is_copy_only());
+    assert!(TextureUsages::TEXTURE_BINDING.is_gpu_readonly());
+    assert!(!(TextureUsages::TEXTURE_BINDING | TextureUsages::RENDER_ATTACHMENT).is_gpu_readonly());
+    assert!(!TextureUsages::STORAGE_BINDING.is_gpu_readonly());
+}
+
+#[test]
+fn usages_requires_features() {
+    let usages = BufferUsages::VERTEX | BufferUsages::BLAS_INPUT;
+    assert_eq!(
+        usages.requires_features(),
+        Features::EXPERIMENTAL_RAY_TRACING_ACCELERATION_STRUCTURE
+    );
+    assert!(usages.contains_unsupported(Features::empty()));
+    assert!(!usages.contains_unsupported(Features::EXPERIMENTAL_RAY_TRACING_ACCELERATION_STRUCTURE));
+    assert!(!BufferUsages::all()
+        .difference(BufferUsages::BLAS_INPUT | BufferUsages::TLAS_INPUT)
+        .contains_unsupported(Features::empty()));
+
+    let usages = TextureUsages::STORAGE_BINDING | TextureUsages::STORAGE_ATOMIC;
+    assert_eq!(usages.requires_features(), Features::TEXTURE_ATOMIC);
+    assert!(!TextureUsages::RENDER_ATTACHMENT.contains_unsupported(Features::empty()));
+}
+
 bitflags::bitflags! {
     /// Similar to `TextureUsages`, but used only for `CommandEncoder::transition_resources`.
     #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
@@ -5859,6 +10248,7 @@ pub struct TextureSelector {
 
 /// Defines the capabilities of a given surface and adapter.
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SurfaceCapabilities {
     /// List of supported formats to use with the given adapter. The first format in the vector is preferred.
     ///
@@ -5889,6 +10279,26 @@ impl Default for SurfaceCapabilities {
     }
 }
 
+#[cfg(feature = "serde")]
+#[test]
+fn surface_capabilities_serde_round_trip() {
+    let caps = SurfaceCapabilities {
+        formats: vec![TextureFormat::Bgra8UnormSrgb, TextureFormat::Rgba16Float],
+        present_modes: vec![PresentMode::Fifo, PresentMode::Mailbox],
+        alpha_modes: vec![
+            CompositeAlphaMode::Opaque,
+            CompositeAlphaMode::PreMultiplied,
+        ],
+        usages: TextureUsages::RENDER_ATTACHMENT | TextureUsages::COPY_SRC,
+    };
+    let json = serde_json::to_string(&caps).unwrap();
+    let round_trip: SurfaceCapabilities = serde_json::from_str(&json).unwrap();
+    assert_eq!(round_trip.formats, caps.formats);
+    assert_eq!(round_trip.present_modes, caps.present_modes);
+    assert_eq!(round_trip.alpha_modes, caps.alpha_modes);
+    assert_eq!(round_trip.usages, caps.usages);
+}
+
 /// Configures a [`Surface`] for presentation.
 ///
 /// [`Surface`]: ../wgpu/struct.Surface.html
@@ -5966,6 +10376,188 @@ impl<V: Clone> SurfaceConfiguration<V> {
     }
 }
 
+impl<V> SurfaceConfiguration<V> {
+    /// Returns a hash of the fields that determine the identity of the swap chain.
+    ///
+    /// Only [`Self::usage`], [`Self::format`], [`Self::width`], [`Self::height`],
+    /// [`Self::present_mode`] and [`Self::alpha_mode`] are hashed.
+    /// [`Self::desired_maximum_frame_latency`] is only a hint and [`Self::view_formats`] do not
+    /// affect the swap chain textures, so they are excluded. Comparing the signatures of two
+    /// configurations is a cheap way to detect whether the surface needs to be reconfigured.
+    ///
+    /// The hash function is fixed, but the value may change between wgpu versions, so
+    /// signatures should not be persisted.
+    #[must_use]
+    pub fn signature(&self) -> u64 {
+        let mut hasher = Fnv1aHasher::default();
+        self.usage.hash(&mut hasher);
+        self.format.hash(&mut hasher);
+        self.width.hash(&mut hasher);
+        self.height.hash(&mut hasher);
+        self.present_mode.hash(&mut hasher);
+        self.alpha_mode.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Returns true if the configuration is supported by the given surface capabilities.
+    ///
+    /// See [`Self::check_supported_by`] for the checks that are performed.
+    #[must_use]
+    pub fn is_supported_by(&self, caps: &SurfaceCapabilities) -> bool {
+        self.check_supported_by(caps).is_ok()
+    }
+
+    /// Checks that the configuration is supported by the given surface capabilities,
+    /// returning the first unsupported field otherwise.
+    ///
+    /// The format, present mode and alpha mode must be part of the capabilities, and the
+    /// usages must be a subset of the supported usages. The automatic modes
+    /// [`PresentMode::AutoVsync`], [`PresentMode::AutoNoVsync`] and
+    /// [`CompositeAlphaMode::Auto`] are always supported, as they fall back to a
+    /// supported mode when configuring the surface.
+    ///
+    /// The size and view formats of the configuration are not checked.
+    pub fn check_supported_by(
+        &self,
+        caps: &SurfaceCapabilities,
+    ) -> Result<(), SurfaceConfigurationError> {
+        if !caps.formats.contains(&self.format) {
+            return Err(SurfaceConfigurationError::UnsupportedFormat(self.format));
+        }
+        let auto_present_mode = matches!(
+            self.present_mode,
+            PresentMode::AutoVsync | PresentMode::AutoNoVsync
+        );
+        if !auto_present_mode && !caps.present_modes.contains(&self.present_mode) {
+            return Err(SurfaceConfigurationError::UnsupportedPresentMode(
+                self.present_mode,
+            ));
+        }
+        if self.alpha_mode != CompositeAlphaMode::Auto
+            && !caps.alpha_modes.contains(&self.alpha_mode)
+        {
+            return Err(SurfaceConfigurationError::UnsupportedAlphaMode(
+                self.alpha_mode,
+            ));
+        }
+        if !caps.usages.contains(self.usage) {
+            return Err(SurfaceConfigurationError::UnsupportedUsage(
+                self.usage.difference(caps.usages),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Error returned by [`SurfaceConfiguration::check_supported_by`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SurfaceConfigurationError {
+    /// The format is not supported by the surface.
+    UnsupportedFormat(TextureFormat),
+    /// The present mode is not supported by the surface.
+    UnsupportedPresentMode(PresentMode),
+    /// The alpha mode is not supported by the surface.
+    UnsupportedAlphaMode(CompositeAlphaMode),
+    /// The contained usages are not supported by the surface.
+    UnsupportedUsage(TextureUsages),
+}
+
+impl core::fmt::Display for SurfaceConfigurationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match *self {
+            Self::UnsupportedFormat(format) => {
+                write!(f, "Surface does not support the format {format:?}")
+            }
+            Self::UnsupportedPresentMode(mode) => {
+                write!(f, "Surface does not support the present mode {mode:?}")
+            }
+            Self::UnsupportedAlphaMode(mode) => {
+                write!(f, "Surface does not support the alpha mode {mode:?}")
+            }
+            Self::UnsupportedUsage(usage) => {
+                write!(f, "Surface does not support the usages {usage:?}")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SurfaceConfigurationError {}
+
+#[test]
+fn surface_configuration_supported_by() {
+    let caps = SurfaceCapabilities {
+        formats: vec![TextureFormat::Bgra8UnormSrgb],
+        present_modes: vec![PresentMode::Fifo],
+        alpha_modes: vec![CompositeAlphaMode::Opaque],
+        usages: TextureUsages::RENDER_ATTACHMENT | TextureUsages::COPY_SRC,
+    };
+    let config = SurfaceConfiguration {
+        usage: TextureUsages::RENDER_ATTACHMENT,
+        format: TextureFormat::Bgra8UnormSrgb,
+        width: 640,
+        height: 480,
+        present_mode: PresentMode::AutoNoVsync,
+        desired_maximum_frame_latency: 2,
+        alpha_mode: CompositeAlphaMode::Auto,
+        view_formats: Vec::<TextureFormat>::new(),
+    };
+    assert!(config.is_supported_by(&caps));
+
+    let mailbox = SurfaceConfiguration {
+        present_mode: PresentMode::Mailbox,
+        ..config.clone()
+    };
+    assert_eq!(
+        mailbox.check_supported_by(&caps),
+        Err(SurfaceConfigurationError::UnsupportedPresentMode(
+            PresentMode::Mailbox
+        ))
+    );
+
+    let storage = SurfaceConfiguration {
+        usage: TextureUsages::RENDER_ATTACHMENT | TextureUsages::STORAGE_BINDING,
+        ..config
+    };
+    assert_eq!(
+        storage.check_supported_by(&caps),
+        Err(SurfaceConfigurationError::UnsupportedUsage(
+            TextureUsages::STORAGE_BINDING
+        ))
+    );
+}
+
+#[test]
+fn surface_configuration_signature() {
+    let config = SurfaceConfiguration {
+        usage: TextureUsages::RENDER_ATTACHMENT,
+        format: TextureFormat::Bgra8UnormSrgb,
+        width: 640,
+        height: 480,
+        present_mode: PresentMode::Fifo,
+        desired_maximum_frame_latency: 2,
+        alpha_mode: CompositeAlphaMode::Opaque,
+        view_formats: Vec::<TextureFormat>::new(),
+    };
+    let hint_only = SurfaceConfiguration {
+        desired_maximum_frame_latency: 1,
+        view_formats: vec![TextureFormat::Bgra8Unorm],
+        ..config.clone()
+    };
+    assert_eq!(config.signature(), hint_only.signature());
+
+    let resized = SurfaceConfiguration {
+        width: 800,
+        ..config.clone()
+    };
+    assert_ne!(config.signature(), resized.signature());
+    let mailbox = SurfaceConfiguration {
+        present_mode: PresentMode::Mailbox,
+        ..config.clone()
+    };
+    assert_ne!(config.signature(), mailbox.signature());
+}
+
 /// Status of the received surface image.
 #[repr(C)]
 #[derive(Debug)]
@@ -5985,6 +10577,42 @@ pub enum SurfaceStatus {
     Unknown,
 }
 
+impl SurfaceStatus {
+    /// Returns true if the received image can be rendered to and presented, that is, for
+    /// [`SurfaceStatus::Good`] and [`SurfaceStatus::Suboptimal`].
+    #[must_use]
+    pub const fn is_usable(&self) -> bool {
+        match *self {
+            Self::Good | Self::Suboptimal => true,
+            Self::Timeout | Self::Outdated | Self::Lost | Self::Unknown => false,
+        }
+    }
+
+    /// Returns true if the surface should be configured again, that is, for
+    /// [`SurfaceStatus::Suboptimal`], [`SurfaceStatus::Outdated`] and [`SurfaceStatus::Lost`].
+    ///
+    /// A [`SurfaceStatus::Suboptimal`] image is still [usable](Self::is_usable), so the
+    /// surface can be reconfigured after presenting it.
+    #[must_use]
+    pub const fn should_reconfigure(&self) -> bool {
+        match *self {
+            Self::Suboptimal | Self::Outdated | Self::Lost => true,
+            Self::Good | Self::Timeout | Self::Unknown => false,
+        }
+    }
+}
+
+#[test]
+fn surface_status_predicates() {
+    let status = |status: SurfaceStatus| (status.is_usable(), status.should_reconfigure());
+    assert_eq!(status(SurfaceStatus::Good), (true, false));
+    assert_eq!(status(SurfaceStatus::Suboptimal), (true, true));
+    assert_eq!(status(SurfaceStatus::Timeout), (false, false));
+    assert_eq!(status(SurfaceStatus::Outdated), (false, true));
+    assert_eq!(status(SurfaceStatus::Lost), (false, true));
+    assert_eq!(status(SurfaceStatus::Unknown), (false, false));
+}
+
 /// Nanosecond timestamp used by the presentation engine.
 ///
 /// The specific clock depends on the window system integration (WSI) API used.
@@ -6031,6 +10659,8 @@ impl PresentationTimestamp {
 /// RGBA double precision color.
 ///
 /// This is not to be used as a generic color type, only for specific wgpu interfaces.
+///
+/// Converts to and from `[f64; 4]` arrays with the components in `[r, g, b, a]` order.
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Default, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -6046,44 +10676,312 @@ pub struct Color {
     pub a: f64,
 }
 
-#[allow(missing_docs)]
-impl Color {
-    pub const TRANSPARENT: Self = Self {
-        r: 0.0,
-        g: 0.0,
-        b: 0.0,
+#[allow(missing_docs)]
+impl Color {
+    pub const TRANSPARENT: Self = Self {
+        r: 0.0,
+        g: 0.0,
+        b: 0.0,
+        a: 0.0,
+    };
+    pub const BLACK: Self = Self {
+        r: 0.0,
+        g: 0.0,
+        b: 0.0,
+        a: 1.0,
+    };
+    pub const WHITE: Self = Self {
+        r: 1.0,
+        g: 1.0,
+        b: 1.0,
+        a: 1.0,
+    };
+    pub const RED: Self = Self {
+        r: 1.0,
+        g: 0.0,
+        b: 0.0,
+        a: 1.0,
+    };
+    pub const GREEN: Self = Self {
+        r: 0.0,
+        g: 1.0,
+        b: 0.0,
+        a: 1.0,
+    };
+    pub const BLUE: Self = Self {
+        r: 0.0,
+        g: 0.0,
+        b: 1.0,
+        a: 1.0,
+    };
+}
+
+impl Color {
+    /// Creates a color from 8-bit components, mapping `0..=255` to `0.0..=1.0`.
+    ///
+    /// No gamma conversion is done, each component is just divided by 255. Use
+    /// [`Color::to_linear`] afterwards if the components are sRGB encoded.
+    #[must_use]
+    pub fn from_rgba8(r: u8, g: u8, b: u8, a: u8) -> Self {
+        Self {
+            r: f64::from(r) / 255.0,
+            g: f64::from(g) / 255.0,
+            b: f64::from(b) / 255.0,
+            a: f64::from(a) / 255.0,
+        }
+    }
+
+    /// Creates a color from a packed `0xRRGGBBAA` value, with red in the highest byte.
+    ///
+    /// Like [`Color::from_rgba8`], no gamma conversion is done.
+    ///
+    /// ```
+    /// # use wgpu_types::Color;
+    /// assert_eq!(Color::from_rgba_hex(0xFF0000FF), Color::RED);
+    /// ```
+    #[must_use]
+    pub fn from_rgba_hex(rgba: u32) -> Self {
+        let [r, g, b, a] = rgba.to_be_bytes();
+        Self::from_rgba8(r, g, b, a)
+    }
+
+    /// Applies `f` to each of the four components, including alpha.
+    ///
+    /// The results are not clamped.
+    #[must_use]
+    pub fn map(self, f: impl Fn(f64) -> f64) -> Self {
+        Self {
+            r: f(self.r),
+            g: f(self.g),
+            b: f(self.b),
+            a: f(self.a),
+        }
+    }
+
+    /// Linearly interpolates each component between `self` at `t = 0.0` and `other`
+    /// at `t = 1.0`.
+    ///
+    /// Neither `t` nor the results are clamped, so values of `t` outside of `0.0..=1.0`
+    /// extrapolate.
+    #[must_use]
+    pub fn lerp(self, other: Self, t: f64) -> Self {
+        let lerp = |a: f64, b: f64| a + (b - a) * t;
+        Self {
+            r: lerp(self.r, other.r),
+            g: lerp(self.g, other.g),
+            b: lerp(self.b, other.b),
+            a: lerp(self.a, other.a),
+        }
+    }
+
+    /// Multiplies the red, green and blue components by `factor`, leaving alpha untouched.
+    ///
+    /// The results are not clamped.
+    #[must_use]
+    pub fn scale_rgb(self, factor: f64) -> Self {
+        Self {
+            r: self.r * factor,
+            g: self.g * factor,
+            b: self.b * factor,
+            a: self.a,
+        }
+    }
+
+    /// Returns true if every component has exactly the same bit pattern as in `other`.
+    ///
+    /// Unlike `==`, a NaN component is equal to a NaN with the same bits, while `0.0` and `-0.0`
+    /// are not equal. As this is deterministic, it is suitable for caching, such as deduplicating
+    /// materials or render pass descriptors by their clear color.
+    #[must_use]
+    pub fn bits_eq(&self, other: &Color) -> bool {
+        self.r.to_bits() == other.r.to_bits()
+            && self.g.to_bits() == other.g.to_bits()
+            && self.b.to_bits() == other.b.to_bits()
+            && self.a.to_bits() == other.a.to_bits()
+    }
+
+    /// Returns true if every component differs from `other` by at most `epsilon`.
+    ///
+    /// This tolerates rounding errors, so it is suitable for tests and for comparing colors
+    /// that went through conversions such as [`Color::to_linear`]. A NaN component is never
+    /// approximately equal to anything.
+    #[must_use]
+    pub fn approx_eq(&self, other: &Color, epsilon: f64) -> bool {
+        let close = |a: f64, b: f64| {
+            let diff = a - b;
+            -epsilon <= diff && diff <= epsilon
+        };
+        close(self.r, other.r)
+            && close(self.g, other.g)
+            && close(self.b, other.b)
+            && close(self.a, other.a)
+    }
+
+    /// Returns the components in `[r, g, b, a]` order, converted to `f32`.
+    ///
+    /// This is the layout expected for a `vec4<f32>` color in a uniform buffer.
+    #[must_use]
+    pub fn to_array_f32(&self) -> [f32; 4] {
+        [self.r as f32, self.g as f32, self.b as f32, self.a as f32]
+    }
+}
+
+impl From<[f64; 4]> for Color {
+    fn from([r, g, b, a]: [f64; 4]) -> Self {
+        Self { r, g, b, a }
+    }
+}
+
+impl From<Color> for [f64; 4] {
+    fn from(color: Color) -> Self {
+        [color.r, color.g, color.b, color.a]
+    }
+}
+
+#[test]
+fn color_ops() {
+    let from = Color::BLACK;
+    let to = Color {
+        r: 1.0,
+        g: 0.5,
+        b: 0.25,
         a: 0.0,
     };
-    pub const BLACK: Self = Self {
-        r: 0.0,
-        g: 0.0,
-        b: 0.0,
-        a: 1.0,
-    };
-    pub const WHITE: Self = Self {
+    assert_eq!(from.lerp(to, 0.0), from);
+    assert_eq!(from.lerp(to, 1.0), to);
+    assert_eq!(
+        from.lerp(to, 0.5),
+        Color {
+            r: 0.5,
+            g: 0.25,
+            b: 0.125,
+            a: 0.5,
+        }
+    );
+    assert_eq!(
+        to.scale_rgb(2.0),
+        Color {
+            r: 2.0,
+            g: 1.0,
+            b: 0.5,
+            a: 0.0,
+        }
+    );
+    assert_eq!(
+        to.map(|c| 1.0 - c),
+        Color {
+            r: 0.0,
+            g: 0.5,
+            b: 0.75,
+            a: 1.0,
+        }
+    );
+}
+
+#[test]
+fn color_array_conversions() {
+    let color = Color {
         r: 1.0,
-        g: 1.0,
-        b: 1.0,
-        a: 1.0,
+        g: 0.5,
+        b: 0.25,
+        a: 0.125,
     };
-    pub const RED: Self = Self {
-        r: 1.0,
-        g: 0.0,
-        b: 0.0,
-        a: 1.0,
+    let array: [f64; 4] = color.into();
+    assert_eq!(array, [1.0, 0.5, 0.25, 0.125]);
+    assert_eq!(Color::from(array), color);
+    assert_eq!(color.to_array_f32(), [1.0, 0.5, 0.25, 0.125]);
+}
+
+#[test]
+fn color_comparison() {
+    let nan = Color {
+        a: f64::NAN,
+        ..Color::BLACK
     };
-    pub const GREEN: Self = Self {
-        r: 0.0,
-        g: 1.0,
-        b: 0.0,
-        a: 1.0,
+    assert_ne!(nan, nan);
+    assert!(nan.bits_eq(&nan));
+    assert!(!nan.approx_eq(&nan, 1.0));
+
+    let negative_zero = Color {
+        r: -0.0,
+        ..Color::BLACK
     };
-    pub const BLUE: Self = Self {
-        r: 0.0,
-        g: 0.0,
+    assert_eq!(negative_zero, Color::BLACK);
+    assert!(!negative_zero.bits_eq(&Color::BLACK));
+    assert!(negative_zero.approx_eq(&Color::BLACK, 0.0));
+
+    let nearly_white = Color::WHITE.map(|c| c - 1e-9);
+    assert!(nearly_white.approx_eq(&Color::WHITE, 1e-6));
+    assert!(!nearly_white.approx_eq(&Color::WHITE, 1e-12));
+    assert!(!nearly_white.bits_eq(&Color::WHITE));
+}
+
+#[cfg(feature = "std")]
+impl Color {
+    /// Converts the color from sRGB encoding to linear, applying the sRGB transfer function
+    /// to the red, green and blue components. Alpha is left untouched.
+    ///
+    /// This is the inverse of [`Color::to_srgb`], up to floating point error.
+    #[must_use]
+    pub fn to_linear(self) -> Self {
+        fn to_linear(c: f64) -> f64 {
+            if c <= 0.04045 {
+                c / 12.92
+            } else {
+                ((c + 0.055) / 1.055).powf(2.4)
+            }
+        }
+
+        Self {
+            r: to_linear(self.r),
+            g: to_linear(self.g),
+            b: to_linear(self.b),
+            a: self.a,
+        }
+    }
+
+    /// Converts the color from linear to sRGB encoding, applying the inverse sRGB transfer
+    /// function to the red, green and blue components. Alpha is left untouched.
+    ///
+    /// This is the inverse of [`Color::to_linear`], up to floating point error.
+    #[must_use]
+    pub fn to_srgb(self) -> Self {
+        fn to_srgb(c: f64) -> f64 {
+            if c <= 0.0031308 {
+                c * 12.92
+            } else {
+                1.055 * c.powf(1.0 / 2.4) - 0.055
+            }
+        }
+
+        Self {
+            r: to_srgb(self.r),
+            g: to_srgb(self.g),
+            b: to_srgb(self.b),
+            a: self.a,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn color_srgb_round_trip() {
+    let color = Color {
+        r: 0.5,
+        g: 0.02,
         b: 1.0,
-        a: 1.0,
+        a: 0.25,
     };
+    let linear = color.to_linear();
+    assert!((linear.r - 0.214_041).abs() < 1e-6);
+    assert_eq!(linear.b, 1.0);
+    assert_eq!(linear.a, 0.25);
+
+    let srgb = linear.to_srgb();
+    for (a, b) in [(srgb.r, color.r), (srgb.g, color.g), (srgb.b, color.b)] {
+        assert!((a - b).abs() < 1e-9);
+    }
+    assert_eq!(Color::BLACK.to_linear(), Color::BLACK);
 }
 
 /// Dimensionality of a texture.
@@ -6105,6 +11003,41 @@ pub enum TextureDimension {
     D3,
 }
 
+impl TextureDimension {
+    /// Returns the dimension of a view of a texture with this dimension and `array_layers`
+    /// layers, when the view does not specify a dimension.
+    ///
+    /// A 2D texture with more than one layer is viewed as a [`TextureViewDimension::D2Array`],
+    /// every other texture is viewed with the matching dimension. The result is always
+    /// compatible with the texture, see [`TextureViewDimension::compatible_texture_dimension`].
+    #[must_use]
+    pub fn default_view_dimension(&self, array_layers: u32) -> TextureViewDimension {
+        match *self {
+            Self::D1 => TextureViewDimension::D1,
+            Self::D2 if array_layers > 1 => TextureViewDimension::D2Array,
+            Self::D2 => TextureViewDimension::D2,
+            Self::D3 => TextureViewDimension::D3,
+        }
+    }
+}
+
+#[test]
+fn texture_dimension_default_view_dimension() {
+    use TextureDimension as Td;
+    use TextureViewDimension as Tvd;
+
+    assert_eq!(Td::D1.default_view_dimension(1), Tvd::D1);
+    assert_eq!(Td::D2.default_view_dimension(1), Tvd::D2);
+    assert_eq!(Td::D2.default_view_dimension(6), Tvd::D2Array);
+    assert_eq!(Td::D3.default_view_dimension(1), Tvd::D3);
+    for dim in [Td::D1, Td::D2, Td::D3] {
+        for layers in [1, 4] {
+            let view = dim.default_view_dimension(layers);
+            assert_eq!(view.compatible_texture_dimension(), dim);
+        }
+    }
+}
+
 /// Origin of a copy from a 2D image.
 ///
 /// Corresponds to [WebGPU `GPUOrigin2D`](
@@ -6124,6 +11057,15 @@ impl Origin2d {
     /// Zero origin.
     pub const ZERO: Self = Self { x: 0, y: 0 };
 
+    /// Returns true if this is the zero origin.
+    ///
+    /// Copies from external images with a non-zero source origin require
+    /// [`DownlevelFlags::UNRESTRICTED_EXTERNAL_TEXTURE_COPIES`].
+    #[must_use]
+    pub const fn is_zero(&self) -> bool {
+        self.x == 0 && self.y == 0
+    }
+
     /// Adds the third dimension to this origin
     #[must_use]
     pub fn to_3d(self, z: u32) -> Origin3d {
@@ -6135,6 +11077,12 @@ impl Origin2d {
     }
 }
 
+impl Default for Origin2d {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
 impl core::fmt::Debug for Origin2d {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         (self.x, self.y).fmt(f)
@@ -6162,6 +11110,12 @@ impl Origin3d {
     /// Zero origin.
     pub const ZERO: Self = Self { x: 0, y: 0, z: 0 };
 
+    /// Returns true if this is the zero origin.
+    #[must_use]
+    pub const fn is_zero(&self) -> bool {
+        self.x == 0 && self.y == 0 && self.z == 0
+    }
+
     /// Removes the third dimension from this origin
     #[must_use]
     pub fn to_2d(self) -> Origin2d {
@@ -6184,6 +11138,16 @@ impl core::fmt::Debug for Origin3d {
     }
 }
 
+#[test]
+fn origin_is_zero() {
+    assert_eq!(Origin2d::default(), Origin2d::ZERO);
+    assert!(Origin2d::default().is_zero());
+    assert!(!Origin2d { x: 0, y: 1 }.is_zero());
+    assert!(Origin3d::default().is_zero());
+    assert!(!Origin3d { x: 0, y: 0, z: 1 }.is_zero());
+    assert!(Origin2d::ZERO.to_3d(0).is_zero());
+}
+
 /// Extent of a texture related operation.
 ///
 /// Corresponds to [WebGPU `GPUExtent3D`](
@@ -6224,6 +11188,36 @@ impl Default for Extent3d {
 }
 
 impl Extent3d {
+    /// Creates the extent of a single 2D image, with `depth_or_array_layers` set to 1.
+    #[must_use]
+    pub const fn new_2d(width: u32, height: u32) -> Self {
+        Self::with_layers(width, height, 1)
+    }
+
+    /// Creates the extent of a 2D array texture with the given number of array `layers`.
+    ///
+    /// This is also used for cube maps, which have 6 layers per cube.
+    #[must_use]
+    pub const fn with_layers(width: u32, height: u32, layers: u32) -> Self {
+        Self {
+            width,
+            height,
+            depth_or_array_layers: layers,
+        }
+    }
+
+    /// Creates the extent of a 3D texture with the given `depth`.
+    ///
+    /// Unlike array layers, the depth of a [`TextureDimension::D3`] texture is mipmapped.
+    #[must_use]
+    pub const fn new_3d(width: u32, height: u32, depth: u32) -> Self {
+        Self {
+            width,
+            height,
+            depth_or_array_layers: depth,
+        }
+    }
+
     /// Calculates the [physical size] backing a texture of the given
     /// format and extent.  This includes padding to the block width
     /// and height of the format.
@@ -6247,10 +11241,22 @@ impl Extent3d {
 
     /// Calculates the maximum possible count of mipmaps.
     ///
-    /// Treats the depth as part of the mipmaps. If calculating
-    /// for a 2DArray texture, which does not mipmap depth, set depth to 1.
+    /// This is the same as [`Self::max_mips_for_dimension`]: for
+    /// [`TextureDimension::D2`], `depth_or_array_layers` is the number of array layers and is
+    /// ignored, only a [`TextureDimension::D3`] texture mipmaps its depth.
     #[must_use]
     pub fn max_mips(&self, dim: TextureDimension) -> u32 {
+        self.max_mips_for_dimension(dim)
+    }
+
+    /// Calculates the maximum possible count of mipmaps of a texture with the given dimension.
+    ///
+    /// The meaning of `depth_or_array_layers` depends on `dim`: the depth of a
+    /// [`TextureDimension::D3`] texture is mipmapped along with its width and height, while the
+    /// array layers of a [`TextureDimension::D2`] texture don't reduce the mip count, so they
+    /// don't need to be set to 1 beforehand.
+    #[must_use]
+    pub fn max_mips_for_dimension(&self, dim: TextureDimension) -> u32 {
         match dim {
             TextureDimension::D1 => 1,
             TextureDimension::D2 => {
@@ -6265,24 +11271,239 @@ impl Extent3d {
     }
 
     /// Calculates the extent at a given mip level.
-    /// Does *not* account for memory size being a multiple of block size.
+    /// Does *not* account for memory size being a multiple of block size,
+    /// use [`Self::physical_size`] on the result for that.
+    ///
+    /// Each dimension is halved per level and clamped to a minimum of 1. Array layers of
+    /// 2D textures are left unchanged, while the depth of 3D textures is halved as well.
     ///
     /// <https://gpuweb.github.io/gpuweb/#logical-miplevel-specific-texture-extent>
     #[must_use]
     pub fn mip_level_size(&self, level: u32, dim: TextureDimension) -> Self {
+        let mip = |size: u32| u32::max(1, size.checked_shr(level).unwrap_or(0));
+
         Self {
-            width: u32::max(1, self.width >> level),
+            width: mip(self.width),
             height: match dim {
                 TextureDimension::D1 => 1,
-                _ => u32::max(1, self.height >> level),
+                _ => mip(self.height),
             },
             depth_or_array_layers: match dim {
                 TextureDimension::D1 => 1,
                 TextureDimension::D2 => self.depth_or_array_layers,
-                TextureDimension::D3 => u32::max(1, self.depth_or_array_layers >> level),
+                TextureDimension::D3 => mip(self.depth_or_array_layers),
             },
         }
     }
+
+    /// Returns the end of a region of this extent starting at `origin`, that is, the
+    /// component-wise sum of the two.
+    ///
+    /// The sum saturates at [`u32::MAX`] instead of overflowing.
+    #[must_use]
+    pub fn end(&self, origin: Origin3d) -> Origin3d {
+        Origin3d {
+            x: origin.x.saturating_add(self.width),
+            y: origin.y.saturating_add(self.height),
+            z: origin.z.saturating_add(self.depth_or_array_layers),
+        }
+    }
+
+    /// Returns true if the region of `size` starting at `origin` fits within this extent.
+    ///
+    /// Regions which extend past [`u32::MAX`] in any dimension never fit.
+    #[must_use]
+    pub fn contains_region(&self, origin: Origin3d, size: Extent3d) -> bool {
+        let fits = |origin: u32, size: u32, extent: u32| {
+            origin.checked_add(size).is_some_and(|end| end <= extent)
+        };
+        fits(origin.x, size.width, self.width)
+            && fits(origin.y, size.height, self.height)
+            && fits(
+                origin.z,
+                size.depth_or_array_layers,
+                self.depth_or_array_layers,
+            )
+    }
+
+    /// Returns true if a texture of the given format and dimension can have this extent.
+    ///
+    /// See [`Self::check_format_requirements`] for the requirements that are checked.
+    #[must_use]
+    pub fn meets_format_requirements(&self, format: TextureFormat, dim: TextureDimension) -> bool {
+        self.check_format_requirements(format, dim).is_ok()
+    }
+
+    /// Checks that a texture of the given format and dimension can have this extent.
+    ///
+    /// The width and height must be multiples of the format's
+    /// [`size_multiple_requirement`](TextureFormat::size_multiple_requirement), which is
+    /// the block size for compressed formats. Additionally, depth-stencil and multi-planar
+    /// formats can only be used for 2D textures, and compressed formats only for 2D or,
+    /// in the case of BCn formats, 3D textures.
+    ///
+    /// This does not check against any [`Limits`], or for features required by the format.
+    pub fn check_format_requirements(
+        &self,
+        format: TextureFormat,
+        dim: TextureDimension,
+    ) -> Result<(), TextureExtentError> {
+        let dimension_allowed = match dim {
+            TextureDimension::D1 => !format.is_compressed(),
+            TextureDimension::D2 => true,
+            TextureDimension::D3 => !format.is_compressed() || format.is_bcn(),
+        };
+        if !dimension_allowed
+            || (dim != TextureDimension::D2
+                && (format.is_depth_stencil_format() || format.is_multi_planar_format()))
+        {
+            return Err(TextureExtentError::InvalidDimension {
+                dimension: dim,
+                format,
+            });
+        }
+
+        let (width_multiple, height_multiple) = format.size_multiple_requirement();
+        if self.width % width_multiple != 0 {
+            return Err(TextureExtentError::WidthNotMultipleOf {
+                width: self.width,
+                multiple: width_multiple,
+                format,
+            });
+        }
+        if self.height % height_multiple != 0 {
+            return Err(TextureExtentError::HeightNotMultipleOf {
+                height: self.height,
+                multiple: height_multiple,
+                format,
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Error returned by [`Extent3d::check_format_requirements`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TextureExtentError {
+    /// The format cannot be used with textures of this dimension.
+    InvalidDimension {
+        /// The dimension of the texture.
+        dimension: TextureDimension,
+        /// The format of the texture.
+        format: TextureFormat,
+    },
+    /// The width is not a multiple of the format's size multiple requirement.
+    WidthNotMultipleOf {
+        /// The width of the extent.
+        width: u32,
+        /// The multiple the width must be of.
+        multiple: u32,
+        /// The format of the texture.
+        format: TextureFormat,
+    },
+    /// The height is not a multiple of the format's size multiple requirement.
+    HeightNotMultipleOf {
+        /// The height of the extent.
+        height: u32,
+        /// The multiple the height must be of.
+        multiple: u32,
+        /// The format of the texture.
+        format: TextureFormat,
+    },
+}
+
+impl core::fmt::Display for TextureExtentError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match *self {
+            Self::InvalidDimension { dimension, format } => {
+                write!(f, "Format {format:?} can't be used for {dimension:?} textures")
+            }
+            Self::WidthNotMultipleOf {
+                width,
+                multiple,
+                format,
+            } => write!(
+                f,
+                "Width {width} is not a multiple of {format:?}'s width multiple requirement ({multiple})"
+            ),
+            Self::HeightNotMultipleOf {
+                height,
+                multiple,
+                format,
+            } => write!(
+                f,
+                "Height {height} is not a multiple of {format:?}'s height multiple requirement ({multiple})"
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TextureExtentError {}
+
+#[test]
+fn extent_contains_region() {
+    let extent = Extent3d {
+        width: 64,
+        height: 32,
+        depth_or_array_layers: 4,
+    };
+    let origin = Origin3d { x: 16, y: 8, z: 1 };
+    let size = Extent3d {
+        width: 48,
+        height: 24,
+        depth_or_array_layers: 3,
+    };
+    assert_eq!(size.end(origin), Origin3d { x: 64, y: 32, z: 4 });
+    assert!(extent.contains_region(origin, size));
+    assert!(!extent.contains_region(Origin3d { x: 17, ..origin }, size));
+    assert!(!extent.contains_region(Origin3d { z: 2, ..origin }, size));
+
+    let origin = Origin3d {
+        x: u32::MAX,
+        ..origin
+    };
+    assert_eq!(size.end(origin).x, u32::MAX);
+    assert!(!Extent3d {
+        width: u32::MAX,
+        ..extent
+    }
+    .contains_region(origin, size));
+}
+
+#[test]
+fn extent_format_requirements() {
+    let extent = Extent3d {
+        width: 6,
+        height: 5,
+        depth_or_array_layers: 1,
+    };
+    assert!(extent.meets_format_requirements(TextureFormat::Rgba8Unorm, TextureDimension::D2));
+    assert_eq!(
+        extent.check_format_requirements(TextureFormat::NV12, TextureDimension::D2),
+        Err(TextureExtentError::HeightNotMultipleOf {
+            height: 5,
+            multiple: 2,
+            format: TextureFormat::NV12
+        })
+    );
+    assert_eq!(
+        extent.check_format_requirements(TextureFormat::Bc1RgbaUnorm, TextureDimension::D2),
+        Err(TextureExtentError::WidthNotMultipleOf {
+            width: 6,
+            multiple: 4,
+            format: TextureFormat::Bc1RgbaUnorm
+        })
+    );
+
+    let extent = Extent3d {
+        width: 8,
+        height: 8,
+        depth_or_array_layers: 3,
+    };
+    assert!(extent.meets_format_requirements(TextureFormat::Bc1RgbaUnorm, TextureDimension::D3));
+    assert!(!extent.meets_format_requirements(TextureFormat::Etc2Rgb8Unorm, TextureDimension::D3));
+    assert!(!extent.meets_format_requirements(TextureFormat::Depth32Float, TextureDimension::D3));
 }
 
 #[test]
@@ -6374,6 +11595,16 @@ fn test_max_mips() {
         .max_mips(TextureDimension::D2),
         8
     );
+    // 2D array, e.g. a cube array
+    assert_eq!(
+        Extent3d {
+            width: 1024,
+            height: 1024,
+            depth_or_array_layers: 6
+        }
+        .max_mips_for_dimension(TextureDimension::D2),
+        11
+    );
     // 3D
     assert_eq!(
         Extent3d {
@@ -6386,6 +11617,77 @@ fn test_max_mips() {
     );
 }
 
+#[test]
+fn extent_constructors() {
+    const SIZE: Extent3d = Extent3d::new_2d(256, 128);
+    assert_eq!(SIZE, Extent3d::with_layers(256, 128, 1));
+    assert_eq!(
+        Extent3d::with_layers(64, 64, 6),
+        Extent3d {
+            width: 64,
+            height: 64,
+            depth_or_array_layers: 6,
+        }
+    );
+    assert_eq!(
+        Extent3d::new_3d(16, 30, 60).max_mips(TextureDimension::D3),
+        6
+    );
+}
+
+#[test]
+fn test_mip_level_size() {
+    let extent = Extent3d {
+        width: 13,
+        height: 7,
+        depth_or_array_layers: 5,
+    };
+    // Array layers are not mipped in 2D textures.
+    assert_eq!(
+        extent.mip_level_size(1, TextureDimension::D2),
+        Extent3d {
+            width: 6,
+            height: 3,
+            depth_or_array_layers: 5
+        }
+    );
+    assert_eq!(
+        extent.mip_level_size(3, TextureDimension::D2),
+        Extent3d {
+            width: 1,
+            height: 1,
+            depth_or_array_layers: 5
+        }
+    );
+    // Depth is mipped in 3D textures.
+    assert_eq!(
+        extent.mip_level_size(2, TextureDimension::D3),
+        Extent3d {
+            width: 3,
+            height: 1,
+            depth_or_array_layers: 1
+        }
+    );
+    assert_eq!(
+        extent.mip_level_size(40, TextureDimension::D3),
+        Extent3d {
+            width: 1,
+            height: 1,
+            depth_or_array_layers: 1
+        }
+    );
+    // The logical size of small mips of compressed textures is smaller than a block.
+    let mip = extent.mip_level_size(2, TextureDimension::D2);
+    assert_eq!(
+        mip.physical_size(TextureFormat::Bc1RgbaUnorm),
+        Extent3d {
+            width: 4,
+            height: 4,
+            depth_or_array_layers: 5
+        }
+    );
+}
+
 /// Describes a `TextureView`.
 ///
 /// For use with `Texture::create_view`.
@@ -6635,6 +11937,32 @@ pub enum TextureAspect {
     Plane2,
 }
 
+bitflags::bitflags! {
+    /// Set of aspects present in a [`TextureFormat`].
+    ///
+    /// Returned by [`TextureFormat::aspects`].
+    #[repr(transparent)]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[cfg_attr(feature = "serde", serde(transparent))]
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+    pub struct TextureAspectFlags: u8 {
+        /// Color aspect.
+        const COLOR = 1 << 0;
+        /// Depth aspect.
+        const DEPTH = 1 << 1;
+        /// Stencil aspect.
+        const STENCIL = 1 << 2;
+        /// Plane 0 of a multi-planar format.
+        const PLANE_0 = 1 << 3;
+        /// Plane 1 of a multi-planar format.
+        const PLANE_1 = 1 << 4;
+        /// Plane 2 of a multi-planar format.
+        const PLANE_2 = 1 << 5;
+        /// Depth and stencil aspects.
+        const DEPTH_STENCIL = Self::DEPTH.bits() | Self::STENCIL.bits();
+    }
+}
+
 /// How edges should be handled in texture addressing.
 ///
 /// Corresponds to [WebGPU `GPUAddressMode`](
@@ -29,3 +29,395 @@ where
         value + alignment - remainder
     }
 }
+
+/// Rounds `value` up to the nearest multiple of `alignment`.
+///
+/// Unlike with many alignment helpers, `alignment` need not be a power of two.
+/// If `alignment` is 0, `value` is returned unchanged.
+///
+/// # Panics
+///
+/// Panics if the result does not fit in a `u64`.
+///
+/// # Examples
+///
+/// ```
+/// # use wgpu_types::math::align_up;
+/// assert_eq!(align_up(253, 16), 256);
+/// assert_eq!(align_up(10, 12), 12);
+/// assert_eq!(align_up(10, 0), 10);
+/// ```
+#[must_use]
+pub const fn align_up(value: u64, alignment: u64) -> u64 {
+    if alignment == 0 {
+        return value;
+    }
+    match value.checked_next_multiple_of(alignment) {
+        Some(aligned) => aligned,
+        None => panic!("aligned value overflows u64"),
+    }
+}
+
+/// Rounds `value` down to the nearest multiple of `alignment`.
+///
+/// Unlike with many alignment helpers, `alignment` need not be a power of two.
+/// If `alignment` is 0, `value` is returned unchanged.
+///
+/// # Examples
+///
+/// ```
+/// # use wgpu_types::math::align_down;
+/// assert_eq!(align_down(253, 16), 240);
+/// assert_eq!(align_down(23, 12), 12);
+/// assert_eq!(align_down(10, 0), 10);
+/// ```
+#[must_use]
+pub const fn align_down(value: u64, alignment: u64) -> u64 {
+    if alignment == 0 {
+        return value;
+    }
+    value - value % alignment
+}
+
+/// Computes the greatest common divisor of `a` and `b`.
+///
+/// Returns 0 if both are 0.
+///
+/// # Examples
+///
+/// ```
+/// # use wgpu_types::math::gcd;
+/// assert_eq!(gcd(12, 18), 6);
+/// assert_eq!(gcd(0, 5), 5);
+/// ```
+#[must_use]
+pub const fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        let remainder = a % b;
+        a = b;
+        b = remainder;
+    }
+    a
+}
+
+/// Computes the least common multiple of `a` and `b`, for example the smallest alignment
+/// satisfying two alignment requirements.
+///
+/// Returns 0 if either is 0.
+///
+/// # Panics
+///
+/// Panics if the result does not fit in a `u64`.
+///
+/// # Examples
+///
+/// ```
+/// # use wgpu_types::math::lcm;
+/// assert_eq!(lcm(4, 6), 12);
+/// assert_eq!(lcm(256, 4), 256);
+/// ```
+#[must_use]
+pub const fn lcm(a: u64, b: u64) -> u64 {
+    if a == 0 || b == 0 {
+        return 0;
+    }
+    match (a / gcd(a, b)).checked_mul(b) {
+        Some(lcm) => lcm,
+        None => panic!("least common multiple overflows u64"),
+    }
+}
+
+/// Returns `2^exponent`, for exponents in the range of normal `f32` values.
+fn exp2i(exponent: i32) -> f32 {
+    debug_assert!((-126..=127).contains(&exponent));
+    f32::from_bits(((exponent + 127) as u32) << 23)
+}
+
+/// Packs a color into the layout of [`TextureFormat::Rgb9e5Ufloat`]: three 9-bit mantissas
+/// sharing a 5-bit exponent, with red in the least significant bits.
+///
+/// Components are clamped to the representable range `[0, 65408]`, and NaN is treated as 0.
+/// Follows the conversion of the `EXT_texture_shared_exponent` specification, rounding to
+/// the nearest representable value.
+///
+/// # Examples
+///
+/// ```
+/// # use wgpu_types::math::{pack_rgb9e5, unpack_rgb9e5};
+/// assert_eq!(pack_rgb9e5(1.0, 1.0, 1.0), 0x8402_0100);
+/// assert_eq!(unpack_rgb9e5(pack_rgb9e5(0.5, 2.0, 0.0)), [0.5, 2.0, 0.0]);
+/// ```
+///
+/// [`TextureFormat::Rgb9e5Ufloat`]: crate::TextureFormat::Rgb9e5Ufloat
+#[must_use]
+pub fn pack_rgb9e5(r: f32, g: f32, b: f32) -> u32 {
+    const MANTISSA_BITS: i32 = 9;
+    const EXPONENT_BIAS: i32 = 15;
+    const MAX_EXPONENT: i32 = 31;
+    // (2^9 - 1) / 2^9 * 2^(31 - 15)
+    const MAX_VALUE: f32 = 65408.0;
+
+    let clamp = |c: f32| if c > 0.0 { c.min(MAX_VALUE) } else { 0.0 };
+    let (r, g, b) = (clamp(r), clamp(g), clamp(b));
+    let max = r.max(g).max(b);
+
+    // floor(log2(max)), clamped below at -EXPONENT_BIAS - 1.
+    let floor_log2 = if max < exp2i(-EXPONENT_BIAS - 1) {
+        -EXPONENT_BIAS - 1
+    } else {
+        ((max.to_bits() >> 23) & 0xff) as i32 - 127
+    };
+    let mut exponent = floor_log2 + 1 + EXPONENT_BIAS;
+    // Values are non-negative, so truncating after adding 0.5 rounds to nearest.
+    let quantize =
+        |c: f32, exponent: i32| (c / exp2i(exponent - EXPONENT_BIAS - MANTISSA_BITS) + 0.5) as u32;
+    if quantize(max, exponent) == 1 << MANTISSA_BITS {
+        exponent += 1;
+    }
+    debug_assert!((0..=MAX_EXPONENT).contains(&exponent));
+
+    quantize(r, exponent)
+        | quantize(g, exponent) << 9
+        | quantize(b, exponent) << 18
+        | (exponent as u32) << 27
+}
+
+/// Unpacks a value in the layout of [`TextureFormat::Rgb9e5Ufloat`] into its red, green and
+/// blue components, the inverse of [`pack_rgb9e5`].
+///
+/// [`TextureFormat::Rgb9e5Ufloat`]: crate::TextureFormat::Rgb9e5Ufloat
+#[must_use]
+pub fn unpack_rgb9e5(packed: u32) -> [f32; 3] {
+    let scale = exp2i((packed >> 27) as i32 - 15 - 9);
+    [
+        (packed & 0x1ff) as f32 * scale,
+        (packed >> 9 & 0x1ff) as f32 * scale,
+        (packed >> 18 & 0x1ff) as f32 * scale,
+    ]
+}
+
+/// Packs a color into the layout of [`TextureFormat::Rgb10a2Unorm`]: 10 bits each for red,
+/// green and blue and 2 bits for alpha, with red in the least significant bits.
+///
+/// Components are clamped to `[0, 1]` and rounded to the nearest representable value, and
+/// NaN is treated as 0.
+///
+/// # Examples
+///
+/// ```
+/// # use wgpu_types::math::pack_rgb10a2_unorm;
+/// assert_eq!(pack_rgb10a2_unorm([1.0, 0.0, 0.0, 1.0]), 0xC000_03FF);
+/// ```
+///
+/// [`TextureFormat::Rgb10a2Unorm`]: crate::TextureFormat::Rgb10a2Unorm
+#[must_use]
+pub fn pack_rgb10a2_unorm(color: [f32; 4]) -> u32 {
+    // `as` saturates, turning NaN into 0.
+    let quantize = |c: f32, max: u32| (c.clamp(0.0, 1.0) * max as f32 + 0.5) as u32;
+    let [r, g, b, a] = color;
+    quantize(r, 0x3ff)
+        | quantize(g, 0x3ff) << 10
+        | quantize(b, 0x3ff) << 20
+        | quantize(a, 0x3) << 30
+}
+
+/// Unpacks a value in the layout of [`TextureFormat::Rgb10a2Unorm`] into its red, green,
+/// blue and alpha components, the inverse of [`pack_rgb10a2_unorm`].
+///
+/// [`TextureFormat::Rgb10a2Unorm`]: crate::TextureFormat::Rgb10a2Unorm
+#[must_use]
+pub fn unpack_rgb10a2_unorm(packed: u32) -> [f32; 4] {
+    [
+        (packed & 0x3ff) as f32 / 1023.0,
+        (packed >> 10 & 0x3ff) as f32 / 1023.0,
+        (packed >> 20 & 0x3ff) as f32 / 1023.0,
+        (packed >> 30) as f32 / 3.0,
+    ]
+}
+
+/// Converts an `f32` to the bits of an IEEE 754 half precision float, as used by
+/// [`VertexFormat::Float16x2`], [`VertexFormat::Float16x4`] and [`TextureFormat::R16Float`].
+///
+/// Rounds to the nearest representable value, with ties rounding to even. Values too large
+/// for half precision become infinity, values too small become subnormals or zero, and NaN
+/// stays NaN. The sign is kept in all cases, including for zero.
+///
+/// # Examples
+///
+/// ```
+/// # use wgpu_types::math::{f16_bits_to_f32, f32_to_f16_bits};
+/// assert_eq!(f32_to_f16_bits(1.0), 0x3C00);
+/// assert_eq!(f32_to_f16_bits(-2.0), 0xC000);
+/// assert_eq!(f16_bits_to_f32(0x3555), 0.33325195);
+/// ```
+///
+/// [`VertexFormat::Float16x2`]: crate::VertexFormat::Float16x2
+/// [`VertexFormat::Float16x4`]: crate::VertexFormat::Float16x4
+/// [`TextureFormat::R16Float`]: crate::TextureFormat::R16Float
+#[must_use]
+pub fn f32_to_f16_bits(value: f32) -> u16 {
+    let bits = value.to_bits();
+    let sign = (bits >> 16) as u16 & 0x8000;
+    let exponent = (bits >> 23 & 0xff) as i32;
+    let mantissa = bits & 0x7f_ffff;
+
+    // Infinity and NaN, keeping NaNs quiet.
+    if exponent == 0xff {
+        let nan = if mantissa != 0 {
+            0x200 | (mantissa >> 13) as u16
+        } else {
+            0
+        };
+        return sign | 0x7c00 | nan;
+    }
+
+    // Drops the lowest `shift` bits of `mantissa`, rounding to nearest even.
+    let round = |mantissa: u32, shift: u32| {
+        let truncated = mantissa >> shift;
+        let remainder = mantissa & ((1 << shift) - 1);
+        let halfway = 1 << (shift - 1);
+        let round_up = remainder > halfway || (remainder == halfway && truncated & 1 != 0);
+        truncated + round_up as u32
+    };
+
+    let half_exponent = exponent - 127 + 15;
+    if half_exponent >= 0x1f {
+        // Too large, round to infinity.
+        sign | 0x7c00
+    } else if half_exponent <= 0 {
+        // Subnormal or zero. Anything below half of the smallest subnormal rounds to zero.
+        if half_exponent < -10 {
+            return sign;
+        }
+        let mantissa = mantissa | 0x80_0000;
+        // Rounding up may carry into the exponent, giving the smallest normal value.
+        sign | round(mantissa, (14 - half_exponent) as u32) as u16
+    } else {
+        // Rounding up may carry into the exponent, up to infinity.
+        let unrounded = (half_exponent as u32) << 23 | mantissa;
+        sign | round(unrounded, 13) as u16
+    }
+}
+
+/// Converts the bits of an IEEE 754 half precision float to an `f32`, the inverse of
+/// [`f32_to_f16_bits`].
+///
+/// The conversion is exact, as every half precision value is representable as an `f32`.
+#[must_use]
+pub fn f16_bits_to_f32(bits: u16) -> f32 {
+    let sign = u32::from(bits & 0x8000) << 16;
+    let exponent = u32::from(bits >> 10 & 0x1f);
+    let mantissa = u32::from(bits & 0x3ff);
+
+    let magnitude = match exponent {
+        // Zero or subnormal, mantissa * 2^-24.
+        0 => (mantissa as f32 * exp2i(-24)).to_bits(),
+        // Infinity or NaN.
+        0x1f => 0x7f80_0000 | mantissa << 13,
+        _ => (exponent + 127 - 15) << 23 | mantissa << 13,
+    };
+    f32::from_bits(sign | magnitude)
+}
+
+#[test]
+fn f16_conversion() {
+    assert_eq!(f32_to_f16_bits(0.0), 0x0000);
+    assert_eq!(f32_to_f16_bits(-0.0), 0x8000);
+    assert_eq!(f32_to_f16_bits(65504.0), 0x7bff);
+    assert_eq!(f32_to_f16_bits(65519.0), 0x7bff);
+    // Overflow, including ties rounding to even.
+    assert_eq!(f32_to_f16_bits(65520.0), 0x7c00);
+    assert_eq!(f32_to_f16_bits(-1e10), 0xfc00);
+    assert_eq!(f32_to_f16_bits(f32::INFINITY), 0x7c00);
+    assert_eq!(f32_to_f16_bits(f32::NEG_INFINITY), 0xfc00);
+    assert!(f16_bits_to_f32(f32_to_f16_bits(f32::NAN)).is_nan());
+    // Ties round to even.
+    assert_eq!(f32_to_f16_bits(1.0 + exp2i(-11)), 0x3c00);
+    assert_eq!(f32_to_f16_bits(1.0 + 3.0 * exp2i(-11)), 0x3c02);
+    // Subnormals.
+    assert_eq!(f32_to_f16_bits(exp2i(-24)), 0x0001);
+    assert_eq!(f32_to_f16_bits(exp2i(-25)), 0x0000);
+    assert_eq!(f32_to_f16_bits(1.5 * exp2i(-25)), 0x0001);
+    assert_eq!(f32_to_f16_bits(-exp2i(-20)), 0x8010);
+    assert_eq!(f32_to_f16_bits(exp2i(-14) - exp2i(-26)), 0x0400);
+    assert_eq!(f16_bits_to_f32(0x0001), exp2i(-24));
+    assert_eq!(f16_bits_to_f32(0x8000).to_bits(), (-0.0f32).to_bits());
+
+    for bits in 0..=u16::MAX {
+        let value = f16_bits_to_f32(bits);
+        if !value.is_nan() {
+            assert_eq!(f32_to_f16_bits(value), bits);
+        }
+    }
+}
+
+#[test]
+fn rgb9e5() {
+    assert_eq!(pack_rgb9e5(0.0, 0.0, 0.0), 0);
+    assert_eq!(pack_rgb9e5(-1.0, f32::NAN, 0.0), 0);
+    assert_eq!(pack_rgb9e5(1.0, 1.0, 1.0), 0x8402_0100);
+    assert_eq!(pack_rgb9e5(65408.0, 65408.0, 65408.0), u32::MAX);
+    assert_eq!(pack_rgb9e5(f32::INFINITY, 1e10, 65408.0), u32::MAX);
+    // Rounding up the largest component bumps the shared exponent.
+    assert_eq!(pack_rgb9e5(0.99999, 0.0, 0.0) >> 27, 16);
+    assert_eq!(
+        unpack_rgb9e5(pack_rgb9e5(0.99999, 0.0, 0.0)),
+        [1.0, 0.0, 0.0]
+    );
+
+    for [r, g, b] in [[0.25, 0.5, 0.75], [100.0, 0.001, 3.5], [1e-5, 2e-5, 3e-5]] {
+        let unpacked = unpack_rgb9e5(pack_rgb9e5(r, g, b));
+        let tolerance = r.max(g).max(b) / 512.0;
+        for (original, unpacked) in [r, g, b].into_iter().zip(unpacked) {
+            assert!((original - unpacked).abs() <= tolerance);
+        }
+    }
+}
+
+#[test]
+fn rgb10a2_unorm() {
+    assert_eq!(pack_rgb10a2_unorm([0.0; 4]), 0);
+    assert_eq!(pack_rgb10a2_unorm([1.0; 4]), u32::MAX);
+    assert_eq!(pack_rgb10a2_unorm([0.0, 1.0, 0.0, 0.0]), 0x000F_FC00);
+    assert_eq!(pack_rgb10a2_unorm([2.0, -1.0, f32::NAN, 0.5]), 0x8000_03FF);
+    assert_eq!(unpack_rgb10a2_unorm(0xC000_03FF), [1.0, 0.0, 0.0, 1.0]);
+
+    let color = [0.1, 0.5, 0.9, 2.0 / 3.0];
+    let unpacked = unpack_rgb10a2_unorm(pack_rgb10a2_unorm(color));
+    for (original, unpacked) in color.into_iter().zip(unpacked) {
+        assert!((original - unpacked).abs() <= 0.5 / 1023.0);
+    }
+}
+
+#[test]
+fn alignment_properties() {
+    for alignment in 0..=40 {
+        for value in 0..=300 {
+            let up = align_up(value, alignment);
+            let down = align_down(value, alignment);
+            assert_eq!(align_up(up, alignment), up);
+            assert_eq!(align_down(down, alignment), down);
+            assert!(down <= value && value <= up);
+            if alignment != 0 {
+                assert_eq!(up % alignment, 0);
+                assert_eq!(down % alignment, 0);
+                assert!(up - down < 2 * alignment);
+                assert_eq!(up, align_to(value, alignment));
+            }
+        }
+    }
+}
+
+#[test]
+fn gcd_lcm() {
+    for a in 0..=50 {
+        for b in 0..=50 {
+            assert_eq!(gcd(a, b), gcd(b, a));
+            assert_eq!(gcd(a, b) * lcm(a, b), a * b);
+            if a != 0 && b != 0 {
+                assert_eq!(lcm(a, b) % a, 0);
+                assert_eq!(lcm(a, b) % b, 0);
+            }
+        }
+    }
+}
@@ -35,11 +35,14 @@ static_assertions::assert_impl_all!(DeviceDescriptor<'_>: Send, Sync);
 impl Device {
     /// Check for resource cleanups and mapping callbacks. Will block if [`Maintain::Wait`] is passed.
     ///
-    /// Return `true` if the queue is empty, or `false` if there are more queue
-    /// submissions still in flight. (Note that, unless access to the [`Queue`] is
-    /// coordinated somehow, this information could be out of date by the time
-    /// the caller receives it. `Queue`s can be shared between threads, so
-    /// other threads could submit new work at any time.)
+    /// Return [`MaintainResult::SubmissionQueueEmpty`] if the queue is empty, or
+    /// [`MaintainResult::Ok`] if there are more queue submissions still in flight.
+    /// (Note that, unless access to the [`Queue`] is coordinated somehow, this
+    /// information could be out of date by the time the caller receives it.
+    /// `Queue`s can be shared between threads, so other threads could submit new
+    /// work at any time.) If a wait was requested and timed out,
+    /// [`MaintainResult::Timeout`] is returned; use [`MaintainResult::panic_on_timeout`]
+    /// to treat this as an error.
     ///
     /// When running on WebGPU, this is a no-op. `Device`s are automatically polled.
     pub fn poll(&self, maintain: Maintain) -> MaintainResult {
@@ -1619,10 +1619,7 @@ impl dispatch::DeviceInterface for CoreDevice {
     fn poll(&self, maintain: crate::Maintain) -> crate::MaintainResult {
         let maintain_inner = maintain.map_index(|i| i.index);
         match self.context.0.device_poll(self.id, maintain_inner) {
-            Ok(done) => match done {
-                true => wgt::MaintainResult::SubmissionQueueEmpty,
-                false => wgt::MaintainResult::Ok,
-            },
+            Ok(result) => result,
             Err(err) => self.context.handle_error_fatal(err, "Device::poll"),
         }
     }
@@ -58,26 +58,34 @@ pub mod util;
 
 pub use api::*;
 pub use wgt::{
-    AdapterInfo, AddressMode, AstcBlock, AstcChannel, Backend, BackendOptions, Backends,
-    BindGroupLayoutEntry, BindingType, BlendComponent, BlendFactor, BlendOperation, BlendState,
-    BufferAddress, BufferBindingType, BufferSize, BufferTransition, BufferUsages, BufferUses,
-    Color, ColorTargetState, ColorWrites, CommandBufferDescriptor, CompareFunction,
-    CompositeAlphaMode, CopyExternalImageDestInfo, CoreCounters, DepthBiasState, DepthStencilState,
-    DeviceLostReason, DeviceType, DownlevelCapabilities, DownlevelFlags, DownlevelLimits,
-    Dx12BackendOptions, Dx12Compiler, DynamicOffset, Extent3d, Face, Features, FilterMode,
-    FrontFace, GlBackendOptions, Gles3MinorVersion, HalCounters, ImageSubresourceRange,
-    IndexFormat, InstanceDescriptor, InstanceFlags, InternalCounters, Limits, MaintainResult,
-    MemoryHints, MultisampleState, Origin2d, Origin3d, PipelineStatisticsTypes, PolygonMode,
-    PowerPreference, PredefinedColorSpace, PresentMode, PresentationTimestamp, PrimitiveState,
-    PrimitiveTopology, PushConstantRange, QueryType, RenderBundleDepthStencil, SamplerBindingType,
-    SamplerBorderColor, ShaderLocation, ShaderModel, ShaderRuntimeChecks, ShaderStages,
-    StencilFaceState, StencilOperation, StencilState, StorageTextureAccess, SurfaceCapabilities,
-    SurfaceStatus, TexelCopyBufferLayout, TextureAspect, TextureDimension, TextureFormat,
+    align_copy_buffer, align_map_offset, compute_vertex_stride, is_copy_aligned,
+    validate_attributes, AdapterInfo, AddressMode, AstcBlock, AstcChannel, Backend, BackendOptions,
+    Backends, BindGroupLayoutEntry, BindingCounts, BindingType, BlendComponent, BlendFactor,
+    BlendOperation, BlendState, BufferAddress, BufferBindingType, BufferSize, BufferTransition,
+    BufferUsageError, BufferUsages, BufferUses, Color, ColorTargetState, ColorWrites,
+    CommandBufferDescriptor, CompareFunction, CompositeAlphaMode, CopyExternalImageDestInfo,
+    CoreCounters, DepthBiasState, DepthStencilState, DeviceLostReason, DeviceRequestValidation,
+    DeviceType, DownlevelCapabilities, DownlevelFlags, DownlevelLimits, Dx12BackendOptions,
+    Dx12Compiler, DynamicOffset, Extent3d, Face, FeatureCategory, Features, FilterMode,
+    FormatClassification, FrontFace, GlBackendOptions, Gles3MinorVersion, HalCounters,
+    ImageSubresourceRange, IndexFormat, InstanceDescriptor, InstanceFlags, InternalCounters,
+    LimitViolation, Limits, LimitsPreset, MaintainKind, MaintainResult, MemoryHints,
+    MemoryHintsError, MultisampleState, Origin2d, Origin3d, ParseBackendError,
+    ParseCompositeAlphaModeError, ParseFeaturesError, ParseLimitsPresetError,
+    ParsePowerPreferenceError, ParsePresentModeError, ParseTextureFormatError,
+    PipelineStatisticsTypes, PolygonMode, PowerPreference, PredefinedColorSpace, PresentMode,
+    PresentationTimestamp, PrimitiveState, PrimitiveStateError, PrimitiveTopology,
+    PushConstantRange, QueryType, RenderBundleDepthStencil, SamplerBindingType, SamplerBorderColor,
+    ShaderLocation, ShaderModel, ShaderRuntimeChecks, ShaderStages, StencilFaceState,
+    StencilOperation, StencilState, StorageTextureAccess, SurfaceCapabilities,
+    SurfaceConfigurationError, SurfaceStatus, TexelCopyBufferLayout, TextureAspect,
+    TextureAspectFlags, TextureDimension, TextureExtentError, TextureFormat,
     TextureFormatFeatureFlags, TextureFormatFeatures, TextureSampleType, TextureTransition,
-    TextureUsages, TextureUses, TextureViewDimension, VertexAttribute, VertexFormat,
-    VertexStepMode, WasmNotSend, WasmNotSendSync, WasmNotSync, COPY_BUFFER_ALIGNMENT,
-    COPY_BYTES_PER_ROW_ALIGNMENT, MAP_ALIGNMENT, PUSH_CONSTANT_ALIGNMENT,
-    QUERY_RESOLVE_BUFFER_ALIGNMENT, QUERY_SET_MAX_QUERIES, QUERY_SIZE, VERTEX_STRIDE_ALIGNMENT,
+    TextureUsages, TextureUses, TextureViewDimension, ValidationLevel, VertexAttribute,
+    VertexFormat, VertexLayoutError, VertexScalarKind, VertexStepMode, WasmNotSend,
+    WasmNotSendSync, WasmNotSync, COPY_BUFFER_ALIGNMENT, COPY_BYTES_PER_ROW_ALIGNMENT,
+    MAP_ALIGNMENT, PUSH_CONSTANT_ALIGNMENT, QUERY_RESOLVE_BUFFER_ALIGNMENT, QUERY_SET_MAX_QUERIES,
+    QUERY_SIZE, VERTEX_STRIDE_ALIGNMENT,
 };
 #[expect(deprecated)]
 pub use wgt::{ImageCopyBuffer, ImageCopyTexture, ImageCopyTextureTagged, ImageDataLayout};